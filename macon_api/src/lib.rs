@@ -2,11 +2,171 @@
 //!
 //! See it for all details.
 //!
+//! ### Features
+//!
+//! * **`std`** (_default_): enable `alloc`.
+//! * **`alloc`**: required by `Panic` and `Result` builders, and by `missing_fields()` function.
+//!
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use core::fmt::Debug;
+
+/// Re-exports used by generated code.
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "alloc")]
+    pub use alloc::{
+        string::String,
+        vec,
+        vec::Vec,
+    };
+
+    /// Type-level count of set members in a field group: none.
+    pub struct Zero;
+    /// Type-level count of set members in a field group: a single one.
+    pub struct One;
+    /// Type-level count of set members in a field group: more than one.
+    pub struct Many;
+
+    /// Type-level addition of field group counts (saturating at [`Many`]).
+    pub trait Add<Rhs> {
+        type Sum;
+    }
+
+    impl<Rhs> Add<Rhs> for Zero {
+        type Sum = Rhs;
+    }
+
+    impl Add<Zero> for One {
+        type Sum = One;
+    }
+
+    impl Add<One> for One {
+        type Sum = Many;
+    }
+
+    impl Add<Many> for One {
+        type Sum = Many;
+    }
 
-use std::fmt::Debug;
+    impl<Rhs> Add<Rhs> for Many {
+        type Sum = Many;
+    }
+
+    /// Type-level count of field group members, listed as nested pairs (`(Head, (Next, ()))`).
+    pub trait Count {
+        type Sum;
+    }
+
+    impl Count for () {
+        type Sum = Zero;
+    }
+
+    impl<Head: Add<Tail::Sum>, Tail: Count> Count for (Head, Tail) {
+        type Sum = <Head as Add<Tail::Sum>>::Sum;
+    }
+
+    /// Position of next value for positional setters (`set()`, `none()`, ...) of `Panic`/`Result` tuple builders.
+    #[derive(Clone,Copy,Debug,Default,PartialEq,Eq,Hash,)]
+    pub struct Cursor {
+        position: usize,
+        failed: ::core::option::Option<usize>,
+    }
+
+    impl Cursor {
+        /// Move to next position, returning current one.
+        pub fn advance(&mut self) -> usize {
+            let position = self.position;
+            self.position += 1;
+            position
+        }
+
+        /// Record position which can't be assigned (only first one is kept).
+        pub fn fail(&mut self, position: usize) {
+            self.failed.get_or_insert(position);
+        }
+
+        /// First position which couldn't be assigned (if any).
+        pub fn failed(&self) -> ::core::option::Option<usize> {
+            self.failed
+        }
+
+        /// Keep current position, and first failure from both cursors.
+        pub fn merge(self, other: Self) -> Self {
+            Self {
+                position: self.position,
+                failed: self.failed.or(other.failed),
+            }
+        }
+    }
+}
+
+/// Expand items only when `alloc` feature is enabled.
+#[doc(hidden)]
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! __alloc {
+    ($($item:tt)*) => { $($item)* };
+}
+
+/// Expand items only when `alloc` feature is enabled.
+#[doc(hidden)]
+#[cfg(not(feature = "alloc"))]
+#[macro_export]
+macro_rules! __alloc {
+    ($($item:tt)*) => {};
+}
+
+/// Type with a generated builder.
+pub trait Buildable {
+    /// Builder type returned by [`Buildable::builder()`].
+    type Builder;
+    /// Create a new builder.
+    fn builder() -> Self::Builder;
+}
+
+/// Generated builder (in a buildable state, for `Typestate` mode).
+pub trait Builder {
+    /// Type of built instance.
+    type Target;
+    /// Type returned by [`Builder::build()`] (e.g. [`Result`] for `Result` mode).
+    type Output;
+    /// Consume builder to build target instance.
+    fn build(self) -> Self::Output;
+}
+
+/// Typestate of a field not set yet (for `Typestate` mode).
+#[derive(Clone,Copy,Debug,Default,PartialEq,Eq,Hash,)]
+pub struct Unset;
+
+/// Typestate of a field already set (for `Typestate` mode).
+#[derive(Clone,Copy,Debug,Default,PartialEq,Eq,Hash,)]
+pub struct Set;
+
+/// Tell if field is set from its typestate (for `Typestate` mode).
+pub trait FieldState {
+    /// `true` for [`Set`], `false` for [`Unset`].
+    const IS_SET: bool;
+    /// Count of set fields, for field groups constraints
+    #[doc(hidden)]
+    type __Count;
+}
+
+impl FieldState for Unset {
+    const IS_SET: bool = false;
+    type __Count = __private::Zero;
+}
+
+impl FieldState for Set {
+    const IS_SET: bool = true;
+    type __Count = __private::One;
+}
 
 /// Builder field type when building struct implementing [`Default`].
-#[derive(Default,)]
+#[derive(Clone,Copy,Default,PartialEq,Eq,Hash,)]
 pub enum Keeping<T> {
     /// Builder field value when keeping data from [`Default`] instance.
     #[default]
@@ -16,8 +176,8 @@ pub enum Keeping<T> {
 }
 
 /// Buidler field type when target field implement [`Default`].
-#[derive(Default,)]
-pub enum Defaulting<T: Default> {
+#[derive(Clone,Copy,Default,PartialEq,Eq,Hash,)]
+pub enum Defaulting<T> {
     /// Builder field value when using [`Default`] one.
     #[default]
     Default,
@@ -26,7 +186,7 @@ pub enum Defaulting<T: Default> {
 }
 
 /// Builder field type for `Panic` or `Result` mode.
-#[derive(Default,)]
+#[derive(Clone,Copy,Default,PartialEq,Eq,Hash,)]
 pub enum Building<T> {
     /// Builder field value when not set.
     #[default]
@@ -35,8 +195,59 @@ pub enum Building<T> {
     Set(T),
 }
 
+/// Builder field state formatter used by generated [`Debug`] implementations.
+pub enum Debugging<'a, T: ?Sized> {
+    /// Field is not set.
+    Undefined,
+    /// Field keeps data from [`Default`] instance.
+    Keep,
+    /// Field uses [`Default`] value.
+    Default,
+    /// Field is set with given value.
+    Set(&'a T),
+    /// Field is set, but value must not be displayed.
+    Redacted,
+}
+
+impl<T: Debug + ?Sized> Debug for Debugging<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Undefined => write!(f, "Undefined"),
+            Self::Keep => write!(f, "Keep"),
+            Self::Default => write!(f, "Default"),
+            Self::Set(value) => f.debug_tuple("Set").field(value).finish(),
+            Self::Redacted => write!(f, "Set(***)"),
+        }
+    }
+}
+
+impl<'a, T: ?Sized> Debugging<'a, T> {
+    /// Hide [set](Debugging::Set) value (for sensitive fields).
+    pub fn redact(self) -> Debugging<'static, ()> {
+        match self {
+            Self::Undefined => Debugging::Undefined,
+            Self::Keep => Debugging::Keep,
+            Self::Default => Debugging::Default,
+            Self::Set(_) | Self::Redacted => Debugging::Redacted,
+        }
+    }
+}
+
+impl<'a, T> Debugging<'a, Defaulting<T>> {
+    /// Convert from `Debugging<Defaulting<T>>` to `Debugging<T>`.
+    pub fn flatten(self) -> Debugging<'a, T> {
+        match self {
+            Self::Undefined => Debugging::Undefined,
+            Self::Keep => Debugging::Keep,
+            Self::Default => Debugging::Default,
+            Self::Set(value) => value.debugging(),
+            Self::Redacted => Debugging::Redacted,
+        }
+    }
+}
+
 impl<T> Debug for Keeping<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::Keep => write!(f, "Keeping::Keep"),
             Self::Set(_) => write!(f, "Keeping::Set(_)"),
@@ -45,6 +256,13 @@ impl<T> Debug for Keeping<T> {
 }
 
 impl<T> Keeping<T> {
+    /// Field state to be formatted by [`Debug`].
+    pub fn debugging(&self) -> Debugging<'_, T> {
+        match self {
+            Self::Keep => Debugging::Keep,
+            Self::Set(value) => Debugging::Set(value),
+        }
+    }
     /// Check if [set](Keeping::Set).
     pub fn is_set(&self) -> bool {
         match self {
@@ -52,6 +270,13 @@ impl<T> Keeping<T> {
             Self::Set(_) => true,
         }
     }
+    /// Borrow [set](Keeping::Set) value, if any.
+    pub fn get(&self) -> Option<&T> {
+        match self {
+            Self::Keep => None,
+            Self::Set(value) => Some(value),
+        }
+    }
     /// Consume to return [set](Keeping::Set) value.
     ///
     /// It shouldn't be called for [Keeping::Keep].
@@ -61,10 +286,93 @@ impl<T> Keeping<T> {
             Self::Set(value) => value,
         }
     }
+    /// Convert from `&Keeping<T>` to `Keeping<&T>`.
+    pub fn as_ref(&self) -> Keeping<&T> {
+        match self {
+            Self::Keep => Keeping::Keep,
+            Self::Set(value) => Keeping::Set(value),
+        }
+    }
+    /// Convert from `&mut Keeping<T>` to `Keeping<&mut T>`.
+    pub fn as_mut(&mut self) -> Keeping<&mut T> {
+        match self {
+            Self::Keep => Keeping::Keep,
+            Self::Set(value) => Keeping::Set(value),
+        }
+    }
+    /// Map [set](Keeping::Set) value, if any.
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Keeping<U> {
+        match self {
+            Self::Keep => Keeping::Keep,
+            Self::Set(value) => Keeping::Set(f(value)),
+        }
+    }
+    /// Take out value, leaving [`Keeping::Keep`] in its place.
+    pub fn take(&mut self) -> Self {
+        ::core::mem::replace(self, Self::Keep)
+    }
+    /// Replace by [set](Keeping::Set) `value`, returning previous one.
+    pub fn replace(&mut self, value: T) -> Self {
+        ::core::mem::replace(self, Self::Set(value))
+    }
+    /// Consume to return [set](Keeping::Set) value, if any.
+    pub fn into_option(self) -> Option<T> {
+        match self {
+            Self::Keep => None,
+            Self::Set(value) => Some(value),
+        }
+    }
+    /// Consume to return [set](Keeping::Set) value or provided `default`.
+    pub fn unwrap_or(self, default: T) -> T {
+        match self {
+            Self::Keep => default,
+            Self::Set(value) => value,
+        }
+    }
+    /// Consume to return [set](Keeping::Set) value or computed one.
+    pub fn unwrap_or_else<F: FnOnce() -> T>(self, f: F) -> T {
+        match self {
+            Self::Keep => f(),
+            Self::Set(value) => value,
+        }
+    }
+    /// Consume to return [set](Keeping::Set) value.
+    ///
+    /// Panics with `message` for [`Keeping::Keep`].
+    pub fn expect(self, message: &str) -> T {
+        match self {
+            Self::Keep => panic!("{}", message),
+            Self::Set(value) => value,
+        }
+    }
+    /// Consume to return [set](Keeping::Set) value.
+    ///
+    /// Panics with message naming `field` for [`Keeping::Keep`].
+    pub fn expect_field(self, field: &str) -> T {
+        match self {
+            Self::Keep => panic!("Field {} is kept from default instance", field),
+            Self::Set(value) => value,
+        }
+    }
 }
 
-impl<T: Default> Debug for Defaulting<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<T> From<Option<T>> for Keeping<T> {
+    fn from(value: Option<T>) -> Self {
+        match value {
+            None => Self::Keep,
+            Some(value) => Self::Set(value),
+        }
+    }
+}
+
+impl<T> From<Keeping<T>> for Option<T> {
+    fn from(value: Keeping<T>) -> Self {
+        value.into_option()
+    }
+}
+
+impl<T> Debug for Defaulting<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::Default => write!(f, "Defaulting::Default"),
             Self::Set(_) => write!(f, "Defaulting::Set(_)"),
@@ -72,6 +380,98 @@ impl<T: Default> Debug for Defaulting<T> {
     }
 }
 
+impl<T> Defaulting<T> {
+    /// Field state to be formatted by [`Debug`].
+    pub fn debugging(&self) -> Debugging<'_, T> {
+        match self {
+            Self::Default => Debugging::Default,
+            Self::Set(value) => Debugging::Set(value),
+        }
+    }
+    /// Check if [set](Defaulting::Set).
+    pub fn is_set(&self) -> bool {
+        match self {
+            Self::Default => false,
+            Self::Set(_) => true,
+        }
+    }
+    /// Borrow [set](Defaulting::Set) value, if any.
+    pub fn get(&self) -> Option<&T> {
+        match self {
+            Self::Default => None,
+            Self::Set(value) => Some(value),
+        }
+    }
+    /// Convert from `&Defaulting<T>` to `Defaulting<&T>`.
+    pub fn as_ref(&self) -> Defaulting<&T> {
+        match self {
+            Self::Default => Defaulting::Default,
+            Self::Set(value) => Defaulting::Set(value),
+        }
+    }
+    /// Convert from `&mut Defaulting<T>` to `Defaulting<&mut T>`.
+    pub fn as_mut(&mut self) -> Defaulting<&mut T> {
+        match self {
+            Self::Default => Defaulting::Default,
+            Self::Set(value) => Defaulting::Set(value),
+        }
+    }
+    /// Map [set](Defaulting::Set) value, if any.
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Defaulting<U> {
+        match self {
+            Self::Default => Defaulting::Default,
+            Self::Set(value) => Defaulting::Set(f(value)),
+        }
+    }
+    /// Take out value, leaving [`Defaulting::Default`] in its place.
+    pub fn take(&mut self) -> Self {
+        ::core::mem::replace(self, Self::Default)
+    }
+    /// Replace by [set](Defaulting::Set) `value`, returning previous one.
+    pub fn replace(&mut self, value: T) -> Self {
+        ::core::mem::replace(self, Self::Set(value))
+    }
+    /// Consume to return [set](Defaulting::Set) value, if any.
+    pub fn into_option(self) -> Option<T> {
+        match self {
+            Self::Default => None,
+            Self::Set(value) => Some(value),
+        }
+    }
+    /// Consume to return [set](Defaulting::Set) value or provided `default`.
+    pub fn unwrap_or(self, default: T) -> T {
+        match self {
+            Self::Default => default,
+            Self::Set(value) => value,
+        }
+    }
+    /// Consume to return [set](Defaulting::Set) value or computed one.
+    pub fn unwrap_or_else<F: FnOnce() -> T>(self, f: F) -> T {
+        match self {
+            Self::Default => f(),
+            Self::Set(value) => value,
+        }
+    }
+    /// Consume to return [set](Defaulting::Set) value.
+    ///
+    /// Panics with `message` for [`Defaulting::Default`].
+    pub fn expect(self, message: &str) -> T {
+        match self {
+            Self::Default => panic!("{}", message),
+            Self::Set(value) => value,
+        }
+    }
+    /// Consume to return [set](Defaulting::Set) value.
+    ///
+    /// Panics with message naming `field` for [`Defaulting::Default`].
+    pub fn expect_field(self, field: &str) -> T {
+        match self {
+            Self::Default => panic!("Field {} is defaulted", field),
+            Self::Set(value) => value,
+        }
+    }
+}
+
 impl<T: Default> Defaulting<T> {
     /// Consume to return [default](Defaulting::Default) or [set](Defaulting::Set) value.
     pub fn unwrap(self) -> T {
@@ -82,8 +482,23 @@ impl<T: Default> Defaulting<T> {
     }
 }
 
+impl<T> From<Option<T>> for Defaulting<T> {
+    fn from(value: Option<T>) -> Self {
+        match value {
+            None => Self::Default,
+            Some(value) => Self::Set(value),
+        }
+    }
+}
+
+impl<T> From<Defaulting<T>> for Option<T> {
+    fn from(value: Defaulting<T>) -> Self {
+        value.into_option()
+    }
+}
+
 impl<T> Debug for Building<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::Undefined => write!(f, "Building::Undefined"),
             Self::Set(_) => write!(f, "Building::Set(_)"),
@@ -92,14 +507,29 @@ impl<T> Debug for Building<T> {
 }
 
 impl<T> Building<T> {
+    /// Field state to be formatted by [`Debug`].
+    pub fn debugging(&self) -> Debugging<'_, T> {
+        match self {
+            Self::Undefined => Debugging::Undefined,
+            Self::Set(value) => Debugging::Set(value),
+        }
+    }
     /// Check if [`Building::Undefined`].
     pub fn is_undefined(&self) -> bool {
+        matches!(self, Self::Undefined)
+    }
+    /// Check if [set](Building::Set).
+    pub fn is_set(&self) -> bool {
+        matches!(self, Self::Set(_))
+    }
+    /// Borrow [set](Building::Set) value, if any.
+    pub fn get(&self) -> Option<&T> {
         match self {
-            Self::Undefined => true,
-            _ => false,
+            Self::Undefined => None,
+            Self::Set(value) => Some(value),
         }
     }
-    /// Consume to return [set](Defaulting::Set) value.
+    /// Consume to return [set](Building::Set) value.
     ///
     /// It shouldn't be called for [`Building::Undefined`].
     pub fn unwrap(self) -> T {
@@ -108,4 +538,200 @@ impl<T> Building<T> {
             _ => panic!("Can't unwrap value from {:#?}", self),
         }
     }
+    /// Convert from `&Building<T>` to `Building<&T>`.
+    pub fn as_ref(&self) -> Building<&T> {
+        match self {
+            Self::Undefined => Building::Undefined,
+            Self::Set(value) => Building::Set(value),
+        }
+    }
+    /// Convert from `&mut Building<T>` to `Building<&mut T>`.
+    pub fn as_mut(&mut self) -> Building<&mut T> {
+        match self {
+            Self::Undefined => Building::Undefined,
+            Self::Set(value) => Building::Set(value),
+        }
+    }
+    /// Map [set](Building::Set) value, if any.
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Building<U> {
+        match self {
+            Self::Undefined => Building::Undefined,
+            Self::Set(value) => Building::Set(f(value)),
+        }
+    }
+    /// Take out value, leaving [`Building::Undefined`] in its place.
+    pub fn take(&mut self) -> Self {
+        ::core::mem::replace(self, Self::Undefined)
+    }
+    /// Replace by [set](Building::Set) `value`, returning previous one.
+    pub fn replace(&mut self, value: T) -> Self {
+        ::core::mem::replace(self, Self::Set(value))
+    }
+    /// Consume to return [set](Building::Set) value, if any.
+    pub fn into_option(self) -> Option<T> {
+        match self {
+            Self::Undefined => None,
+            Self::Set(value) => Some(value),
+        }
+    }
+    /// Consume to return [set](Building::Set) value or provided `default`.
+    pub fn unwrap_or(self, default: T) -> T {
+        match self {
+            Self::Undefined => default,
+            Self::Set(value) => value,
+        }
+    }
+    /// Consume to return [set](Building::Set) value or computed one.
+    pub fn unwrap_or_else<F: FnOnce() -> T>(self, f: F) -> T {
+        match self {
+            Self::Undefined => f(),
+            Self::Set(value) => value,
+        }
+    }
+    /// Consume to return [set](Building::Set) value.
+    ///
+    /// Panics with `message` for [`Building::Undefined`].
+    pub fn expect(self, message: &str) -> T {
+        match self {
+            Self::Undefined => panic!("{}", message),
+            Self::Set(value) => value,
+        }
+    }
+    /// Consume to return [set](Building::Set) value.
+    ///
+    /// Panics with message naming `field` for [`Building::Undefined`].
+    pub fn expect_field(self, field: &str) -> T {
+        match self {
+            Self::Undefined => panic!("Field {} is missing", field),
+            Self::Set(value) => value,
+        }
+    }
+}
+
+impl<T> From<Option<T>> for Building<T> {
+    fn from(value: Option<T>) -> Self {
+        match value {
+            None => Self::Undefined,
+            Some(value) => Self::Set(value),
+        }
+    }
+}
+
+impl<T> From<Building<T>> for Option<T> {
+    fn from(value: Building<T>) -> Self {
+        value.into_option()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn standard_traits() {
+        let keeping = Keeping::Set(42);
+        let copy = keeping;
+        assert_eq!(keeping.clone(), copy);
+        assert_ne!(Keeping::Keep, copy);
+        assert_eq!(2, HashSet::from([Keeping::Keep, Keeping::Set(42), copy]).len());
+
+        let defaulting = Defaulting::Set(42);
+        let copy = defaulting;
+        assert_eq!(defaulting.clone(), copy);
+        assert_ne!(Defaulting::Default, copy);
+        assert_eq!(2, HashSet::from([Defaulting::Default, Defaulting::Set(42), copy]).len());
+
+        let building = Building::Set(42);
+        let copy = building;
+        assert_eq!(building.clone(), copy);
+        assert_ne!(Building::Undefined, copy);
+        assert_eq!(2, HashSet::from([Building::Undefined, Building::Set(42), copy]).len());
+    }
+
+    #[test]
+    fn cursor() {
+        let mut cursor = __private::Cursor::default();
+        assert_eq!(0, cursor.advance());
+        assert_eq!(1, cursor.advance());
+        assert_eq!(None, cursor.failed());
+        cursor.fail(1);
+        cursor.fail(2);
+        assert_eq!(Some(1), cursor.failed());
+
+        let mut other = __private::Cursor::default();
+        other.fail(0);
+        let mut merged = __private::Cursor::default().merge(other);
+        assert_eq!(Some(0), merged.failed());
+        assert_eq!(0, merged.advance());
+    }
+
+    #[test]
+    fn debugging() {
+        assert_eq!("Undefined", format!("{:?}", Building::<usize>::Undefined.debugging()));
+        assert_eq!("Set(42)", format!("{:?}", Building::Set(42).debugging()));
+        assert_eq!("Set(***)", format!("{:?}", Building::Set(42).debugging().redact()));
+        assert_eq!("Keep", format!("{:?}", Keeping::<Defaulting<usize>>::Keep.debugging().flatten()));
+        assert_eq!("Default", format!("{:?}", Keeping::Set(Defaulting::<usize>::Default).debugging().flatten()));
+        assert_eq!("Set(42)", format!("{:?}", Keeping::Set(Defaulting::Set(42)).debugging().flatten()));
+    }
+
+    #[test]
+    fn keeping_api() {
+        let mut keeping = Keeping::Set(String::from("foobar"));
+        assert_eq!(Keeping::Set(6), keeping.as_ref().map(String::len));
+        if let Keeping::Set(value) = keeping.as_mut() {
+            value.push('!');
+        }
+        assert_eq!(Keeping::Set(String::from("foobar!")), keeping.replace(String::from("barfoo")));
+        assert_eq!(Keeping::Set(String::from("barfoo")), keeping.take());
+        assert_eq!(Keeping::Keep, keeping);
+        assert_eq!(None, keeping.clone().into_option());
+        assert_eq!(String::from("default"), keeping.unwrap_or_else(|| String::from("default")));
+        assert_eq!(Keeping::Set(42), Keeping::from(Some(42)));
+        assert_eq!(Some(42), Option::from(Keeping::Set(42)));
+    }
+
+    #[test]
+    #[should_panic(expected = "Field foobar is kept from default instance")]
+    fn keeping_expect_field() {
+        Keeping::<usize>::Keep.expect_field("foobar");
+    }
+
+    #[test]
+    fn defaulting_api() {
+        let mut defaulting = Defaulting::Set(42);
+        let copy = defaulting;
+        assert_eq!(Defaulting::Set(&42), copy.as_ref());
+        assert_eq!(Defaulting::Set(42), defaulting.take());
+        assert_eq!(Defaulting::Default, defaulting);
+        assert_eq!(0, defaulting.unwrap());
+        assert_eq!(1, defaulting.unwrap_or(1));
+        assert_eq!(Defaulting::Default, Defaulting::<usize>::from(None));
+        assert_eq!(Some(42), copy.into_option());
+        assert_eq!(42, copy.expect("Unset foobar"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Unset foobar")]
+    fn defaulting_expect() {
+        Defaulting::<usize>::Default.expect("Unset foobar");
+    }
+
+    #[test]
+    fn building_api() {
+        let mut building = Building::Undefined;
+        assert_eq!(Building::Undefined, building.replace(42));
+        *building.as_mut().unwrap() += 1;
+        assert_eq!(Building::Set(86), building.map(|value| value * 2));
+        assert_eq!(43, building.expect_field("foobar"));
+        assert_eq!(Some(43), Option::from(building));
+        assert_eq!(Building::Set(42), Building::from(Some(42)));
+    }
+
+    #[test]
+    #[should_panic(expected = "Field foobar is missing")]
+    fn building_expect_field() {
+        Building::<usize>::Undefined.expect_field("foobar");
+    }
 }
@@ -6,21 +6,37 @@ use std::collections::HashMap;
 use proc_macro2::Span;
 use syn::{
     meta::ParseNestedMeta,
+    parse::Parse,
     spanned::Spanned,
     Attribute,
     DeriveInput,
     Field,
     Ident,
+    LitStr,
     Meta,
     MetaList,
+    Path,
     Result,
+    Token,
     Type,
 };
 
 #[derive(Debug, Default, PartialEq)]
 pub struct StructBuilder {
     mode: Setting<String>,
+    style: Setting<String>,
     default: Setting<()>,
+    withers: Setting<()>,
+    patch: Setting<()>,
+    clone: Setting<()>,
+    derives: Vec<Path>,
+    attrs: Vec<Meta>,
+    krate: Setting<Path>,
+    konst: Setting<()>,
+    overwrite: Setting<()>,
+    ordered: Setting<()>,
+    dynamic: Setting<()>,
+    panic_message: Setting<String>,
     fields: StructBuilderFields,
 }
 
@@ -36,6 +52,18 @@ pub struct FieldBuilder {
     option: Setting<Type>,
     default: Setting<()>,
     into: Setting<()>,
+    group: Setting<FieldGroup>,
+    setter_attrs: Vec<Meta>,
+    sensitive: Setting<()>,
+    overwrite: Setting<()>,
+    explicit: Setting<()>,
+    name: Setting<Ident>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldGroup {
+    name: Ident,
+    kind: Setting<Ident>,
 }
 
 #[derive(Debug, Default)]
@@ -51,6 +79,13 @@ impl StructBuilder {
         &mut self.mode
     }
 
+    pub fn style(&self) -> &Setting<String> {
+        &self.style
+    }
+    pub fn style_mut(&mut self) -> &mut Setting<String> {
+        &mut self.style
+    }
+
     pub fn default(&self) -> &Setting<()> {
         &self.default
     }
@@ -58,6 +93,83 @@ impl StructBuilder {
         &mut self.default
     }
 
+    pub fn withers(&self) -> &Setting<()> {
+        &self.withers
+    }
+    pub fn withers_mut(&mut self) -> &mut Setting<()> {
+        &mut self.withers
+    }
+
+    pub fn patch(&self) -> &Setting<()> {
+        &self.patch
+    }
+    pub fn patch_mut(&mut self) -> &mut Setting<()> {
+        &mut self.patch
+    }
+
+    pub fn clone(&self) -> &Setting<()> {
+        &self.clone
+    }
+    pub fn clone_mut(&mut self) -> &mut Setting<()> {
+        &mut self.clone
+    }
+
+    pub fn derives(&self) -> &Vec<Path> {
+        &self.derives
+    }
+    pub fn derives_mut(&mut self) -> &mut Vec<Path> {
+        &mut self.derives
+    }
+
+    pub fn attrs(&self) -> &Vec<Meta> {
+        &self.attrs
+    }
+    pub fn attrs_mut(&mut self) -> &mut Vec<Meta> {
+        &mut self.attrs
+    }
+
+    pub fn krate(&self) -> &Setting<Path> {
+        &self.krate
+    }
+    pub fn krate_mut(&mut self) -> &mut Setting<Path> {
+        &mut self.krate
+    }
+
+    pub fn konst(&self) -> &Setting<()> {
+        &self.konst
+    }
+    pub fn konst_mut(&mut self) -> &mut Setting<()> {
+        &mut self.konst
+    }
+
+    pub fn overwrite(&self) -> &Setting<()> {
+        &self.overwrite
+    }
+    pub fn overwrite_mut(&mut self) -> &mut Setting<()> {
+        &mut self.overwrite
+    }
+
+    pub fn ordered(&self) -> &Setting<()> {
+        &self.ordered
+    }
+    pub fn ordered_mut(&mut self) -> &mut Setting<()> {
+        &mut self.ordered
+    }
+
+    pub fn dynamic(&self) -> &Setting<()> {
+        &self.dynamic
+    }
+    pub fn dynamic_mut(&mut self) -> &mut Setting<()> {
+        &mut self.dynamic
+    }
+
+    pub fn panic_message(&self) -> &Setting<String> {
+        &self.panic_message
+    }
+    pub fn panic_message_mut(&mut self) -> &mut Setting<String> {
+        &mut self.panic_message
+    }
+
     pub fn fields(&self) -> &StructBuilderFields {
         &self.fields
     }
@@ -89,6 +201,13 @@ impl StructBuilder {
                     .parse()
                     .map_err_context("Unable to parse into Ident mode value for struct builder attribute")?;
                 self.mode = Setting::enable(value.to_string(), value.span());
+            } else if nested.path.is_ident("style") {
+                let value: Ident = nested
+                    .value()
+                    .map_err_context("Unable to parse style value for struct builder attribute")?
+                    .parse()
+                    .map_err_context("Unable to parse into Ident style value for struct builder attribute")?;
+                self.style = Setting::enable(value.to_string(), value.span());
             } else if nested.path.is_ident("Option") {
                 //TODO proc_macro_diagnostic https://github.com/rust-lang/rust/issues/54140
                 eprintln!("WARNING: macon: Option at struct level be included in nested fields. e.g. `#[builder(fields(Option))]`");
@@ -102,6 +221,51 @@ impl StructBuilder {
                 eprintln!("WARNING: macon: Into at struct level be included in nested fields. e.g. `#[builder(fields(Option))]`");
                 self.fields.into = Setting::<()>::from_parse_nested_meta(nested)
                     .map_err_context("Unable to parse Into for struct builder attribute")?;
+            } else if nested.path.is_ident("withers") {
+                self.withers = Setting::<()>::from_parse_nested_meta(nested)
+                    .map_err_context("Unable to parse withers for struct builder attribute")?;
+            } else if nested.path.is_ident("patch") {
+                self.patch = Setting::<()>::from_parse_nested_meta(nested)
+                    .map_err_context("Unable to parse patch for struct builder attribute")?;
+            } else if nested.path.is_ident("clone") {
+                self.clone = Setting::<()>::from_parse_nested_meta(nested)
+                    .map_err_context("Unable to parse clone for struct builder attribute")?;
+            } else if nested.path.is_ident("crate") {
+                let value: Path = nested
+                    .value()
+                    .map_err_context("Unable to parse crate value for struct builder attribute")?
+                    .parse()
+                    .map_err_context("Unable to parse into Path crate value for struct builder attribute")?;
+                let span = value.span();
+                self.krate = Setting::enable(value, span);
+            } else if nested.path.is_ident("const") {
+                self.konst = Setting::<()>::from_parse_nested_meta(nested)
+                    .map_err_context("Unable to parse const for struct builder attribute")?;
+            } else if nested.path.is_ident("overwrite") {
+                self.overwrite = Setting::<()>::from_parse_nested_meta(nested)
+                    .map_err_context("Unable to parse overwrite for struct builder attribute")?;
+            } else if nested.path.is_ident("ordered") {
+                self.ordered = Setting::<()>::from_parse_nested_meta(nested)
+                    .map_err_context("Unable to parse ordered for struct builder attribute")?;
+            } else if nested.path.is_ident("dynamic") {
+                self.dynamic = Setting::<()>::from_parse_nested_meta(nested)
+                    .map_err_context("Unable to parse dynamic for struct builder attribute")?;
+            } else if nested.path.is_ident("panic_message") {
+                let value: LitStr = nested
+                    .value()
+                    .map_err_context("Unable to parse panic_message value for struct builder attribute")?
+                    .parse()
+                    .map_err_context("Unable to parse into string literal panic_message value for struct builder attribute")?;
+                self.panic_message = Setting::enable(value.value(), value.span());
+            } else if nested.path.is_ident("derive") {
+                nested.parse_nested_meta(|item| {
+                    self.derives.push(item.path);
+                    Ok(())
+                }).map_err_context("Unable to parse derive for struct builder attribute")?;
+            } else if nested.path.is_ident("attr") {
+                let attrs = parse_metas(nested)
+                    .map_err_context("Unable to parse attr for struct builder attribute")?;
+                self.attrs.extend(attrs);
             } else if nested.path.is_ident("fields") {
                 self.fields.with_parse_nested_meta(nested)?;
             } else {
@@ -130,6 +294,7 @@ impl StructBuilderFields {
     pub fn into_(&self) -> &Setting<()> {
         &self.into
     }
+    #[allow(clippy::wrong_self_convention)]
     pub fn into_mut(&mut self) -> &mut Setting<()> {
         &mut self.into
     }
@@ -167,6 +332,30 @@ impl FieldBuilder {
         &self.into
     }
 
+    pub fn group(&self) -> &Setting<FieldGroup> {
+        &self.group
+    }
+
+    pub fn setter_attrs(&self) -> &Vec<Meta> {
+        &self.setter_attrs
+    }
+
+    pub fn sensitive(&self) -> &Setting<()> {
+        &self.sensitive
+    }
+
+    pub fn overwrite(&self) -> &Setting<()> {
+        &self.overwrite
+    }
+
+    pub fn explicit(&self) -> &Setting<()> {
+        &self.explicit
+    }
+
+    pub fn name(&self) -> &Setting<Ident> {
+        &self.name
+    }
+
     pub fn from_field(field: &Field) -> Result<Self> {
         let mut field_attributes: Self = Default::default();
         for attr in &field.attrs {
@@ -207,13 +396,51 @@ impl FieldBuilder {
                     return Err(nested.error(format!("Default has been already specified ({:?}) for field builder attribute", self.option)));
                 }
                 self.default = Setting::<()>::from_parse_nested_meta(nested)
-                    .map_err_context(format!("Unable to parse Default value for field builder attribute"))?;
+                    .map_err_context("Unable to parse Default value for field builder attribute")?;
             } else if nested.path.is_ident("Into") {
                 if self.into.is_defined() {
                     return Err(nested.error(format!("Into has been already specified ({:?}) for field builder attribute", self.option)));
                 }
                 self.into = Setting::<()>::from_parse_nested_meta(nested)
-                    .map_err_context(format!("Unable to parse Into value for field builder attribute"))?;
+                    .map_err_context("Unable to parse Into value for field builder attribute")?;
+            } else if nested.path.is_ident("group") {
+                if self.group.is_defined() {
+                    return Err(nested.error(format!("group has been already specified ({:?}) for field builder attribute", self.group)));
+                }
+                self.group = FieldGroup::from_parse_nested_meta(nested)
+                    .map_err_context("Unable to parse group value for field builder attribute")?;
+            } else if nested.path.is_ident("setter_attr") {
+                let attrs = parse_metas(nested)
+                    .map_err_context("Unable to parse setter_attr value for field builder attribute")?;
+                self.setter_attrs.extend(attrs);
+            } else if nested.path.is_ident("sensitive") {
+                if self.sensitive.is_defined() {
+                    return Err(nested.error(format!("sensitive has been already specified ({:?}) for field builder attribute", self.sensitive)));
+                }
+                self.sensitive = Setting::<()>::from_parse_nested_meta(nested)
+                    .map_err_context("Unable to parse sensitive value for field builder attribute")?;
+            } else if nested.path.is_ident("overwrite") {
+                if self.overwrite.is_defined() {
+                    return Err(nested.error(format!("overwrite has been already specified ({:?}) for field builder attribute", self.overwrite)));
+                }
+                self.overwrite = Setting::<()>::from_parse_nested_meta(nested)
+                    .map_err_context("Unable to parse overwrite value for field builder attribute")?;
+            } else if nested.path.is_ident("explicit") {
+                if self.explicit.is_defined() {
+                    return Err(nested.error(format!("explicit has been already specified ({:?}) for field builder attribute", self.explicit)));
+                }
+                self.explicit = Setting::<()>::from_parse_nested_meta(nested)
+                    .map_err_context("Unable to parse explicit value for field builder attribute")?;
+            } else if nested.path.is_ident("name") {
+                if self.name.is_defined() {
+                    return Err(nested.error(format!("name has been already specified ({:?}) for field builder attribute", self.name)));
+                }
+                let value: Ident = nested
+                    .value()
+                    .map_err_context("Unable to parse name value for field builder attribute")?
+                    .parse()
+                    .map_err_context("Unable to parse into Ident name value for field builder attribute")?;
+                self.name = Setting::enable(value.clone(), value.span());
             } else {
                 return Err(nested.error(format!("Unsupported option {:?} for field builder attribute", nested.path)));
             }
@@ -222,6 +449,45 @@ impl FieldBuilder {
     }
 }
 
+impl FieldGroup {
+    pub fn name(&self) -> &Ident {
+        &self.name
+    }
+
+    pub fn kind(&self) -> &Setting<Ident> {
+        &self.kind
+    }
+
+    fn from_parse_nested_meta(nested: ParseNestedMeta) -> Result<Setting<Self>> {
+        let span = nested.input.span();
+        let mut name: Option<Ident> = None;
+        let mut kind: Setting<Ident> = Setting::undefined();
+        nested.parse_nested_meta(|item| {
+            let ident = item.path.require_ident()?.clone();
+            if name.is_none() {
+                name = Some(ident);
+            } else if kind.is_undefined() {
+                kind = Setting::enable(ident.clone(), ident.span());
+            } else {
+                return Err(item.error(format!("Unexpected value {} after group kind", ident)));
+            }
+            Ok(())
+        })?;
+        match name {
+            Some(name) => Ok(Setting::enable(Self { name, kind }, span)),
+            None => Err(nested.error("Missing group name")),
+        }
+    }
+}
+
+/// Parse parenthesized list of attributes content (e.g. `attr(inline, doc = "...")`)
+fn parse_metas(nested: ParseNestedMeta) -> Result<Vec<Meta>> {
+    let content;
+    syn::parenthesized!(content in nested.input);
+    let metas = content.parse_terminated(Meta::parse, Token![,])?;
+    Ok(metas.into_iter().collect())
+}
+
 impl Derives {
     pub fn types(&self) -> &HashMap<String, Span> {
         &self.types
@@ -270,7 +536,10 @@ pub mod tests {
     use std::collections::HashSet;
 
     use super::*;
-    use quote::quote;
+    use quote::{
+        format_ident,
+        quote,
+    };
     use syn::{
         parse::Parser,
         parse_quote,
@@ -422,6 +691,201 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn struct_builder_attribute_style() {
+        let derive_input: DeriveInput = parse_quote! {
+            #[builder(mode=Result, style=MutRef)]
+            struct Foobar;
+        };
+        let builder = StructBuilder::from_input(&derive_input)
+            .expect("StructBuilder::from_input");
+        assert_eq!(
+            builder.mode,
+            Setting::enable("Result".to_string(), span()),
+            "mode",
+        );
+        assert_eq!(
+            builder.style,
+            Setting::enable("MutRef".to_string(), span()),
+            "style",
+        );
+    }
+
+    #[test]
+    fn struct_builder_attribute_withers() {
+        let derive_input: DeriveInput = parse_quote! {
+            #[builder(withers)]
+            struct Foobar;
+        };
+        let builder = StructBuilder::from_input(&derive_input)
+            .expect("StructBuilder::from_input");
+        assert_eq!(
+            builder.withers,
+            Setting::enable((), span()),
+            "withers",
+        );
+        assert_eq!(
+            builder.default,
+            Setting::undefined(),
+            "default",
+        );
+    }
+
+    #[test]
+    fn struct_builder_attribute_patch() {
+        let derive_input: DeriveInput = parse_quote! {
+            #[builder(patch)]
+            struct Foobar;
+        };
+        let builder = StructBuilder::from_input(&derive_input)
+            .expect("StructBuilder::from_input");
+        assert_eq!(
+            builder.patch,
+            Setting::enable((), span()),
+            "patch",
+        );
+        assert_eq!(
+            builder.withers,
+            Setting::undefined(),
+            "withers",
+        );
+    }
+
+    #[test]
+    fn struct_builder_attribute_derive_attr() {
+        let derive_input: DeriveInput = parse_quote! {
+            #[builder(derive(Clone, core::fmt::Debug), attr(must_use), attr(allow(dead_code), doc = "Foobar"))]
+            struct Foobar;
+        };
+        let builder = StructBuilder::from_input(&derive_input)
+            .expect("StructBuilder::from_input");
+        let expected_derives: Vec<Path> = vec![
+            parse_quote!(Clone),
+            parse_quote!(core::fmt::Debug),
+        ];
+        assert_eq!(
+            builder.derives,
+            expected_derives,
+            "derives",
+        );
+        let expected_attrs: Vec<Meta> = vec![
+            parse_quote!(must_use),
+            parse_quote!(allow(dead_code)),
+            parse_quote!(doc = "Foobar"),
+        ];
+        assert_eq!(
+            builder.attrs,
+            expected_attrs,
+            "attrs",
+        );
+    }
+
+    #[test]
+    fn struct_builder_attribute_crate() {
+        let derive_input: DeriveInput = parse_quote! {
+            #[builder(crate = my_prelude::macon)]
+            struct Foobar;
+        };
+        let builder = StructBuilder::from_input(&derive_input)
+            .expect("StructBuilder::from_input");
+        let expected: Path = parse_quote!(my_prelude::macon);
+        assert_eq!(
+            builder.krate,
+            Setting::enable(expected, span()),
+            "krate",
+        );
+    }
+
+    #[test]
+    fn struct_builder_attribute_const() {
+        let derive_input: DeriveInput = parse_quote! {
+            #[builder(const)]
+            struct Foobar;
+        };
+        let builder = StructBuilder::from_input(&derive_input)
+            .expect("StructBuilder::from_input");
+        assert_eq!(
+            builder.konst,
+            Setting::enable((), span()),
+            "konst",
+        );
+    }
+
+    #[test]
+    fn struct_builder_attribute_overwrite() {
+        let derive_input: DeriveInput = parse_quote! {
+            #[builder(overwrite)]
+            struct Foobar;
+        };
+        let builder = StructBuilder::from_input(&derive_input)
+            .expect("StructBuilder::from_input");
+        assert_eq!(
+            builder.overwrite,
+            Setting::enable((), span()),
+            "overwrite",
+        );
+    }
+
+    #[test]
+    fn struct_builder_attribute_ordered() {
+        let derive_input: DeriveInput = parse_quote! {
+            #[builder(ordered)]
+            struct Foobar;
+        };
+        let builder = StructBuilder::from_input(&derive_input)
+            .expect("StructBuilder::from_input");
+        assert_eq!(
+            builder.ordered,
+            Setting::enable((), span()),
+            "ordered",
+        );
+    }
+
+    #[test]
+    fn struct_builder_attribute_dynamic() {
+        let derive_input: DeriveInput = parse_quote! {
+            #[builder(dynamic)]
+            struct Foobar;
+        };
+        let builder = StructBuilder::from_input(&derive_input)
+            .expect("StructBuilder::from_input");
+        assert_eq!(
+            builder.dynamic,
+            Setting::enable((), span()),
+            "dynamic",
+        );
+    }
+
+    #[test]
+    fn struct_builder_attribute_panic_message() {
+        let derive_input: DeriveInput = parse_quote! {
+            #[builder(mode=Panic, panic_message = "Invalid configuration")]
+            struct Foobar;
+        };
+        let builder = StructBuilder::from_input(&derive_input)
+            .expect("StructBuilder::from_input");
+        assert_eq!(
+            builder.panic_message,
+            Setting::enable(String::from("Invalid configuration"), span()),
+            "panic_message",
+        );
+    }
+
+    #[test]
+    fn struct_builder_attribute_clone_disabled() {
+        let derive_input: DeriveInput = parse_quote! {
+            #[builder(clone=!)]
+            struct Foobar;
+        };
+        let builder = StructBuilder::from_input(&derive_input)
+            .expect("StructBuilder::from_input");
+        assert_eq!(
+            builder.clone,
+            Setting::disable(span()),
+            "clone",
+        );
+    }
+
     #[test]
     fn struct_builder_attribute_into_enabled() {
         let derive_input: DeriveInput = parse_quote! {
@@ -953,6 +1417,149 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn field_builder_attribute_sensitive() {
+        let field = Field::parse_named.parse2(quote! {
+            #[builder(sensitive)]
+            password: String
+        })
+            .expect("Field::parse_named");
+        let builder = FieldBuilder::from_field(&field)
+            .expect("FieldBuilder::from_field");
+        assert_eq!(
+            builder.sensitive,
+            Setting::enable((), span()),
+            "sensitive",
+        );
+    }
+
+    #[test]
+    fn field_builder_attribute_explicit() {
+        let field = Field::parse_named.parse2(quote! {
+            #[builder(explicit)]
+            tls: Option<String>
+        })
+            .expect("Field::parse_named");
+        let builder = FieldBuilder::from_field(&field)
+            .expect("FieldBuilder::from_field");
+        assert_eq!(
+            builder.explicit,
+            Setting::enable((), span()),
+            "explicit",
+        );
+    }
+
+    #[test]
+    fn field_builder_attribute_name() {
+        let field = Field::parse_unnamed.parse2(quote! {
+            #[builder(name = red)]
+            u8
+        })
+            .expect("Field::parse_unnamed");
+        let builder = FieldBuilder::from_field(&field)
+            .expect("FieldBuilder::from_field");
+        assert_eq!(
+            builder.name,
+            Setting::enable(format_ident!("red"), span()),
+            "name",
+        );
+    }
+
+    #[test]
+    fn field_builder_attribute_overwrite_disabled() {
+        let field = Field::parse_named.parse2(quote! {
+            #[builder(overwrite=!)]
+            port: u16
+        })
+            .expect("Field::parse_named");
+        let builder = FieldBuilder::from_field(&field)
+            .expect("FieldBuilder::from_field");
+        assert_eq!(
+            builder.overwrite,
+            Setting::disable(span()),
+            "overwrite",
+        );
+    }
+
+    #[test]
+    fn field_builder_attribute_setter_attr() {
+        let field = Field::parse_named.parse2(quote! {
+            #[builder(setter_attr(inline, must_use = "foobar"), setter_attr(doc = "Foobar"))]
+            foobar: Foobar
+        })
+            .expect("Field::parse_named");
+        let builder = FieldBuilder::from_field(&field)
+            .expect("FieldBuilder::from_field");
+        let expected: Vec<Meta> = vec![
+            parse_quote!(inline),
+            parse_quote!(must_use = "foobar"),
+            parse_quote!(doc = "Foobar"),
+        ];
+        assert_eq!(
+            builder.setter_attrs,
+            expected,
+            "setter_attrs",
+        );
+    }
+
+    #[test]
+    fn field_builder_attribute_group() {
+        let field = Field::parse_named.parse2(quote! {
+            #[builder(group(auth, exactly_one))]
+            foobar: Option<Foobar>
+        })
+            .expect("Field::parse_unnamed");
+        let builder = FieldBuilder::from_field(&field)
+            .expect("FieldBuilder::from_field");
+        assert_eq!(
+            builder.group.value().map(|g| g.name.to_string()),
+            Some(String::from("auth")),
+            "group.name",
+        );
+        assert_eq!(
+            builder.group.value().and_then(|g| g.kind.value()).map(|k| k.to_string()),
+            Some(String::from("exactly_one")),
+            "group.kind",
+        );
+    }
+
+    #[test]
+    fn field_builder_attribute_group_without_kind() {
+        let field = Field::parse_named.parse2(quote! {
+            #[builder(group(auth))]
+            foobar: Option<Foobar>
+        })
+            .expect("Field::parse_unnamed");
+        let builder = FieldBuilder::from_field(&field)
+            .expect("FieldBuilder::from_field");
+        assert_eq!(
+            builder.group.value().map(|g| g.name.to_string()),
+            Some(String::from("auth")),
+            "group.name",
+        );
+        assert_eq!(
+            builder.group.value().map(|g| g.kind.clone()),
+            Some(Setting::undefined()),
+            "group.kind",
+        );
+    }
+
+    #[test]
+    fn field_builder_attribute_group_too_many_values() {
+        let field = Field::parse_named.parse2(quote! {
+            #[builder(group(auth, exactly_one, at_most_one))]
+            foobar: Option<Foobar>
+        })
+            .expect("Field::parse_unnamed");
+        let actual = FieldBuilder::from_field(&field)
+            .map(|_| ())
+            .map_err(|e| e.to_string());
+        assert_eq!(
+            actual,
+            Err(String::from("Unable to parse group value for field builder attribute: Unexpected value at_most_one after group kind")),
+        );
+    }
+
     #[test]
     fn derives() {
         let derive_input: DeriveInput = parse_quote! {
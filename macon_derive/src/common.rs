@@ -35,8 +35,8 @@ impl<T: Clone> Clone for Setting<T> {
 fn clone(&self) -> Self {
     match self {
         Self::Undefined => Self::Undefined,
-        Self::Disabled { span } => Self::Disabled { span: span.clone() },
-        Self::Enabled { value, span }=> Self::Enabled { value: value.clone(), span: span.clone() },
+        Self::Disabled { span } => Self::Disabled { span: *span },
+        Self::Enabled { value, span }=> Self::Enabled { value: value.clone(), span: *span },
     }
 }
 }
@@ -11,7 +11,7 @@ use syn::{
 
 pub fn get() -> &'static Configuration {
     static CONFIGURATION: OnceLock<Configuration> = OnceLock::new();
-    CONFIGURATION.get_or_init(|| Default::default())
+    CONFIGURATION.get_or_init(Default::default)
 }
 
 #[derive(Debug,)]
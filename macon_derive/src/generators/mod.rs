@@ -4,9 +4,16 @@ pub use crate::model::{
     Properties,
     Property,
     Setter,
+    Style,
+};
+pub use proc_macro2::{
+    Literal,
+    TokenStream,
+};
+pub use quote::{
+    format_ident,
+    quote,
 };
-pub use proc_macro2::TokenStream;
-pub use quote::quote;
 
 pub mod typestate;
 pub mod result_panic;
@@ -26,10 +33,227 @@ pub trait Generator {
     fn impl_target(&self) -> TokenStream {
         let target = &self.builder().target;
         let builder_name = &self.builder().ident;
+        if self.builder().konst.is_enabled() {
+            let init = self.properties().typestate_init();
+            quote! {
+                impl #target {
+                    pub const fn builder() -> #builder_name {
+                        #builder_name #init
+                    }
+                }
+            }
+        } else {
+            quote! {
+                impl #target {
+                    pub fn builder() -> #builder_name {
+                        <#builder_name as ::core::default::Default>::default()
+                    }
+                }
+            }
+        }
+    }
+
+    /// Generate `::macon::Buildable` implementation for target struct
+    fn impl_target_buildable(&self) -> TokenStream {
+        let target = &self.builder().target;
+        let builder_name = &self.builder().ident;
+        let krate = &self.builder().krate;
+        quote! {
+            impl #krate::Buildable for #target {
+                type Builder = #builder_name;
+                fn builder() -> Self::Builder {
+                    #target::builder()
+                }
+            }
+        }
+    }
+
+    /// Builder type when all fields are set
+    fn builder_complete(&self) -> TokenStream;
+
+    /// Generate `Debug::fmt()` content from builder fields formatting
+    fn debug_fmt(&self, fields: TokenStream) -> TokenStream {
+        let name = self.builder().ident.to_string();
+        if fields.is_empty() {
+            return quote!(f.write_str(#name));
+        }
+        let debug = if self.builder().is_tuple {
+            quote!(f.debug_tuple(#name))
+        } else {
+            quote!(f.debug_struct(#name))
+        };
+        quote! {
+            let mut debug = #debug;
+            #fields
+            debug.finish()
+        }
+    }
+
+    /// Generate `impl` block to convert target struct back into a builder with all fields set:
+    ///
+    /// * `into_builder()` function
+    /// * `to_builder()` function (only when target struct derives [`Clone`])
+    fn impl_target_into_builder(&self) -> TokenStream {
+        let target = &self.builder().target;
+        let builder_complete = self.builder_complete();
+        let to_builder = if self.builder().clone.is_enabled() {
+            quote! {
+                pub fn to_builder(&self) -> #builder_complete {
+                    <#builder_complete as ::core::convert::From<#target>>::from(::core::clone::Clone::clone(self))
+                }
+            }
+        } else {
+            quote!()
+        };
+        quote! {
+            impl #target {
+                pub fn into_builder(self) -> #builder_complete {
+                    <#builder_complete as ::core::convert::From<#target>>::from(self)
+                }
+                #to_builder
+            }
+        }
+    }
+
+    /// Generate `impl` block to add `with_<field>()` functions to target struct (only when `withers` is enabled)
+    fn impl_target_withers(&self) -> TokenStream {
+        if ! self.builder().withers.is_enabled() {
+            return quote!();
+        }
+        let target = &self.builder().target;
+        let withers = self.properties().to_token(|f| {
+            let id = f.id();
+            let ident = &f.ident;
+            let wither = f.wither();
+            let typevar = f.typevar();
+            let ty = f.ty_into();
+            let (generic, argtype) = if f.into.is_disabled() {
+                (quote!(), quote!(#ty))
+            } else {
+                (quote!(#typevar: ::core::convert::Into<#ty>), quote!(#typevar))
+            };
+            let value_standard = f.setter_value(Setter::Standard);
+            let wither_option = if f.option.is_enabled() {
+                let wither_none = f.wither_none();
+                let value_none = f.setter_value(Setter::None);
+                let wither_optional = f.wither_optional();
+                let value_optional = f.setter_value(Setter::Optional);
+                quote! {
+                    pub fn #wither_none(mut self) -> Self {
+                        self.#id = #value_none;
+                        self
+                    }
+
+                    pub fn #wither_optional<#generic>(mut self, #ident: ::core::option::Option<#argtype>) -> Self {
+                        self.#id = #value_optional;
+                        self
+                    }
+                }
+            } else {
+                quote!()
+            };
+            quote! {
+                pub fn #wither<#generic>(mut self, #ident: #argtype) -> Self {
+                    self.#id = #value_standard;
+                    self
+                }
+                #wither_option
+            }
+        });
         quote! {
             impl #target {
-                pub fn builder() -> #builder_name {
-                    <#builder_name as ::core::default::Default>::default()
+                #withers
+            }
+        }
+    }
+
+    /// Generate patch struct (with builder extra derives) and its `impl` block (only when `patch` is enabled):
+    ///
+    /// * fluent field setters
+    /// * `merge()` function
+    /// * `apply()` function
+    fn patch(&self) -> TokenStream {
+        let patch = match self.builder().patch.value() {
+            Some(patch) => patch,
+            None => return quote!(),
+        };
+        let vis = &self.builder().vis;
+        let target = &self.builder().target;
+        let fields = self.properties().patch_fields();
+        let delim = if self.builder().is_tuple {
+            quote!(;)
+        } else {
+            quote!()
+        };
+        let setters = self.properties().to_token(|f| {
+            let id = f.id();
+            let ident = &f.ident;
+            let setter = f.setter();
+            let typevar = f.typevar();
+            let ty = f.ty_into();
+            let (generic, argtype) = if f.into.is_disabled() {
+                (quote!(), quote!(#ty))
+            } else {
+                (quote!(#typevar: ::core::convert::Into<#ty>), quote!(#typevar))
+            };
+            let value_standard = f.setter_value(Setter::Standard);
+            let setter_option = if f.option.is_enabled() {
+                let setter_none = f.setter_none();
+                let value_none = f.setter_value(Setter::None);
+                let setter_optional = f.setter_optional();
+                let value_optional = f.setter_value(Setter::Optional);
+                quote! {
+                    pub fn #setter_none(mut self) -> Self {
+                        self.#id = ::core::option::Option::Some(#value_none);
+                        self
+                    }
+
+                    pub fn #setter_optional<#generic>(mut self, #ident: ::core::option::Option<#argtype>) -> Self {
+                        self.#id = ::core::option::Option::Some(#value_optional);
+                        self
+                    }
+                }
+            } else {
+                quote!()
+            };
+            let setter_default = if f.default.is_enabled() {
+                let setter_default = f.setter_default();
+                quote! {
+                    pub fn #setter_default(mut self) -> Self {
+                        self.#id = ::core::option::Option::Some(::core::default::Default::default());
+                        self
+                    }
+                }
+            } else {
+                quote!()
+            };
+            quote! {
+                pub fn #setter<#generic>(mut self, #ident: #argtype) -> Self {
+                    self.#id = ::core::option::Option::Some(#value_standard);
+                    self
+                }
+                #setter_option
+                #setter_default
+            }
+        });
+        let merge = self.properties().patch_merge();
+        let apply = self.properties().patch_apply();
+        let derives = self.builder().derives
+            .iter()
+            .filter(|path| ! path.is_ident("Default"));
+        quote! {
+            #[derive(Default, #(#derives,)*)]
+            #vis struct #patch #fields #delim
+
+            impl #patch {
+                #setters
+
+                pub fn merge(self, other: #patch) -> #patch {
+                    #patch #merge
+                }
+
+                pub fn apply(self, target: &mut #target) {
+                    #apply
                 }
             }
         }
@@ -49,6 +273,51 @@ impl From<Builder> for Box<dyn Generator> {
 
 #[cfg(test)]
 pub mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn crate_path() {
+        for mode in ["Typestate", "Panic", "Result"] {
+            let mode = format_ident!("{}", mode);
+            let builder = Builder::from_input(parse_quote! {
+                #[builder(mode=#mode, crate=my_prelude::runtime, withers, patch)]
+                #[derive(Default, Clone)]
+                struct Foobar {
+                    #[builder(sensitive)]
+                    foo: usize,
+                    bar: Option<String>,
+                    baz: ::std::path::PathBuf,
+                }
+            }).expect("Builder::from_input");
+            let generator: Box<dyn Generator> = builder.into();
+            let generated = generator.all().to_string();
+            assert!(!generated.contains(":: macon"), "{}: {}", mode, generated);
+            assert!(generated.contains("my_prelude :: runtime :: Keeping"), "{}: {}", mode, generated);
+        }
+    }
+
+    #[test]
+    fn typestate_diagnostics() {
+        let builder = Builder::from_input(parse_quote! {
+            struct Foobar {
+                path1: ::std::path::PathBuf,
+                max_size: Option<usize>,
+                #[builder(group(auth, at_most_one))]
+                token: Option<String>,
+            }
+        }).expect("Builder::from_input");
+        let generator: Box<dyn Generator> = builder.into();
+        let generated = generator.all().to_string();
+        assert!(generated.contains("trait FoobarBuilderHasPath1"), "{}", generated);
+        assert!(generated.contains("\"field `path1` of `Foobar` must be set before calling build()\""), "{}", generated);
+        assert!(generated.contains("trait FoobarBuilderTokenUnset"), "{}", generated);
+        assert!(generated.contains("\"field `token` was already set\""), "{}", generated);
+        assert!(generated.contains("\"field `token` of `Foobar` is not set\""), "{}", generated);
+        assert!(!generated.contains("trait FoobarBuilderMaxSizeUnset"), "{}", generated);
+        assert!(!generated.contains("trait FoobarBuilderHasMaxSize"), "{}", generated);
+    }
+
     #[test]
     fn check_as_ref() {
         struct Foo {
@@ -21,15 +21,16 @@ impl ResultPanicGenerator {
     /// Generate builder struct
     pub fn struct_builder(&self) -> TokenStream {
         let vis = &self.builder.vis;
-        let fields = self.properties().result_fields();
+        let fields = self.properties().result_fields(&self.builder.krate);
         let builder_name = &self.builder.ident;
         let delim = if self.builder.is_tuple {
             quote!(;)
         } else {
             quote!()
         };
+        let attrs = self.builder.struct_attrs();
         quote! {
-            #[derive(Default,)]
+            #attrs
             #vis struct #builder_name #fields #delim
         }
     }
@@ -37,25 +38,63 @@ impl ResultPanicGenerator {
     /// Generate `impl` block for generated builder struct:
     ///
     /// * fluent field setters ([`Self::impl_builder_setters()`])
+    /// * positional setters for tuples ([`Self::impl_builder_setters_positional()`] and [`Self::positions()`])
+    /// * field getters and missing fields ([`Self::impl_builder_getters()`])
+    /// * builder merge functions ([`Self::impl_builder_merge()`])
     /// * final `build()` function ([`Self::impl_builder_build()`])
     pub fn impl_builder(&self) -> TokenStream {
         let builder_name = &self.builder.ident;
         let impl_builder_setters = self.impl_builder_setters();
+        let impl_builder_setters_positional = self.impl_builder_setters_positional();
+        let positions = self.positions();
+        let impl_builder_getters = self.impl_builder_getters();
+        let impl_builder_merge = self.impl_builder_merge();
         let impl_builder_build = self.impl_builder_build();
         let impl_builder_from = self.impl_builder_from();
+        let impl_builder_from_target = self.impl_builder_from_target();
+        let impl_builder_clone = self.impl_builder_clone();
+        let impl_builder_debug = self.impl_builder_debug();
+        let impl_builder_trait = self.impl_builder_trait();
         quote! {
             impl #builder_name {
                 #impl_builder_setters
+                #impl_builder_setters_positional
+                #impl_builder_getters
+                #impl_builder_merge
                 #impl_builder_build
             }
             #impl_builder_from
+            #impl_builder_from_target
+            #impl_builder_clone
+            #impl_builder_debug
+            #impl_builder_trait
+            #positions
+        }
+    }
+
+    /// Setters receiver and returned type, according to builder style
+    pub fn setter_receiver(&self) -> (TokenStream, TokenStream) {
+        match self.builder.style {
+            Style::Owned => (quote!(mut self), quote!(Self)),
+            Style::MutRef => (quote!(&mut self), quote!(&mut Self)),
+        }
+    }
+
+    /// Attribute reporting panic location at caller (only for `Panic` mode)
+    pub fn track_caller(&self) -> TokenStream {
+        if self.builder.mode == Mode::Panic {
+            quote!(#[track_caller])
+        } else {
+            quote!()
         }
     }
 
     /// Generate fluent field setters
     pub fn impl_builder_setters(&self) -> TokenStream {
+        let (receiver, output) = self.setter_receiver();
         self.properties().to_token(|f| {
             let setter = f.setter();
+            let attrs = f.setter_attrs();
             let typevar = f.typevar();
             let ident = &f.ident;
             let ty = f.ty_into();
@@ -72,7 +111,8 @@ impl ResultPanicGenerator {
             let setter_standard = {
                 let assign_standard = f.result_assign(Setter::Standard);
                 quote! {
-                    pub fn #setter<#generic>(mut self, #ident: #argtype) -> Self {
+                    #attrs
+                    pub fn #setter<#generic>(#receiver, #ident: #argtype) -> #output {
                         #assign_standard
                         self
                     }
@@ -84,12 +124,14 @@ impl ResultPanicGenerator {
                 let setter_optional = f.setter_optional();
                 let assign_optional= f.result_assign(Setter::Optional);
                 quote! {
-                    pub fn #setter_none(mut self) -> Self {
+                    #attrs
+                    pub fn #setter_none(#receiver) -> #output {
                         #assign_none
                         self
                     }
 
-                    pub fn #setter_optional<#generic>(mut self, #ident: ::core::option::Option<#argtype>) -> Self {
+                    #attrs
+                    pub fn #setter_optional<#generic>(#receiver, #ident: ::core::option::Option<#argtype>) -> #output {
                         #assign_optional
                         self
                     }
@@ -101,7 +143,8 @@ impl ResultPanicGenerator {
                 let setter_keep = f.setter_keep();
                 let assign_keep = f.result_assign(Setter::Keep);
                 quote! {
-                    pub fn #setter_keep(mut self) -> Self {
+                    #attrs
+                    pub fn #setter_keep(#receiver) -> #output {
                         #assign_keep
                         self
                     }
@@ -113,7 +156,8 @@ impl ResultPanicGenerator {
                 let setter_default = f.setter_default();
                 let assign_default = f.result_assign(Setter::Default);
                 quote! {
-                    pub fn #setter_default(mut self) -> Self {
+                    #attrs
+                    pub fn #setter_default(#receiver) -> #output {
                         #assign_default
                         self
                     }
@@ -130,33 +174,308 @@ impl ResultPanicGenerator {
         })
     }
 
+    /// Generate positional setters (`set()`, `none()`, `keep()` and `default()`) for tuple builder, assigning fields in order
+    /// from a runtime cursor. Positions which can't be assigned are reported by `build()`
+    pub fn impl_builder_setters_positional(&self) -> TokenStream {
+        let cursor = match self.properties().result_cursor() {
+            Some(cursor) => cursor,
+            None => return quote!(),
+        };
+        let builder_name = &self.builder.ident;
+        let trait_ident = format_ident!("{}Position", builder_name);
+        let (receiver, output) = self.setter_receiver();
+        let this = match self.builder.style {
+            Style::Owned => quote!(&mut self),
+            Style::MutRef => quote!(self),
+        };
+        let setter = |name: &str, filter: &dyn Fn(&Property) -> bool, setter: Setter| {
+            if ! self.properties().iter().any(filter) {
+                return quote!();
+            }
+            let name = format_ident!("{}", name);
+            let assigns = self.properties().to_token(|f| {
+                if ! filter(f) {
+                    return quote!();
+                }
+                let ordinal = Literal::usize_unsuffixed(f.ordinal);
+                let assign = f.result_assign(setter);
+                quote!(#ordinal => { #assign },)
+            });
+            quote! {
+                pub fn #name(#receiver) -> #output {
+                    match self.#cursor.advance() {
+                        #assigns
+                        position => self.#cursor.fail(position),
+                    }
+                    self
+                }
+            }
+        };
+        let setter_none = setter("none", &|f| f.option.is_enabled(), Setter::None);
+        let setter_keep = if self.builder.default.is_enabled() {
+            setter("keep", &|f| f.struct_default.is_enabled(), Setter::Keep)
+        } else {
+            quote!()
+        };
+        let setter_default = setter("default", &|f| f.default.is_enabled(), Setter::Default);
+        quote! {
+            pub fn set<V, const FIELD: usize>(#receiver, value: V) -> #output where Self: #trait_ident<V, FIELD> {
+                <Self as #trait_ident<V, FIELD>>::__set_position(#this, value);
+                self
+            }
+
+            #setter_none
+            #setter_keep
+            #setter_default
+        }
+    }
+
+    /// Generate trait dispatching positional `set()` value to fields of its type (once per distinctly written type). Impls are
+    /// keyed by first field ordinal, as types written differently may still be the same (e.g. alias or fully qualified path)
+    pub fn positions(&self) -> TokenStream {
+        let cursor = match self.properties().result_cursor() {
+            Some(cursor) => cursor,
+            None => return quote!(),
+        };
+        let vis = &self.builder.vis;
+        let builder_name = &self.builder.ident;
+        let target = self.builder.target.to_string();
+        let trait_ident = format_ident!("{}Position", builder_name);
+        let message = format!("no field of `{}` has type `{{V}}`", target);
+        let label = "positional `set()` requires exact field type (no `Into` conversion)";
+        let mut types: Vec<(String, &syn::Type, Vec<&Property>)> = vec![];
+        for property in self.properties().iter() {
+            let ty = property.ty_into();
+            let key = ty.to_token_stream().to_string();
+            match types.iter_mut().find(|(k, _, _)| *k == key) {
+                Some((_, _, properties)) => properties.push(property),
+                None => types.push((key, ty, vec![property])),
+            }
+        }
+        let impls = types
+            .into_iter()
+            .map(|(_, ty, properties)| {
+                let first = Literal::usize_unsuffixed(properties[0].ordinal);
+                let assigns = properties
+                    .into_iter()
+                    .map(|f| {
+                        let ordinal = Literal::usize_unsuffixed(f.ordinal);
+                        let mut value = quote!(value);
+                        if f.option.is_enabled() {
+                            value = quote!(::core::option::Option::Some(#value));
+                        }
+                        let id = f.id();
+                        let value = f.result_set(value);
+                        quote!(#ordinal => self.#id = #value,)
+                    });
+                quote! {
+                    impl #trait_ident<#ty, #first> for #builder_name {
+                        fn __set_position(&mut self, value: #ty) {
+                            match self.#cursor.advance() {
+                                #(#assigns)*
+                                position => self.#cursor.fail(position),
+                            }
+                        }
+                    }
+                }
+            });
+        quote! {
+            #[doc(hidden)]
+            #[diagnostic::on_unimplemented(message = #message, label = #label)]
+            #vis trait #trait_ident<V, const FIELD: usize> {
+                fn __set_position(&mut self, value: V);
+            }
+
+            #(#impls)*
+        }
+    }
+
+    /// Check positional setters cursor (only for tuples), reporting first position which couldn't be assigned
+    pub fn impl_builder_build_cursor(&self) -> TokenStream {
+        let cursor = match self.properties().result_cursor() {
+            Some(cursor) => cursor,
+            None => return quote!(),
+        };
+        let messages = self.properties().to_token(|f| {
+            let ordinal = Literal::usize_unsuffixed(f.ordinal);
+            let message = format!("Field {} can't be assigned from positional setter", f.label());
+            quote!(#ordinal => #message,)
+        });
+        quote! {
+            if let ::core::option::Option::Some(position) = self.#cursor.failed() {
+                errors.push(match position {
+                    #messages
+                    _ => "Too many positional values",
+                }.into());
+            }
+        }
+    }
+
+    /// Generate field getters, field checkers and `missing_fields()` function
+    pub fn impl_builder_getters(&self) -> TokenStream {
+        let krate = &self.builder.krate;
+        let getters = self.properties().to_token(|f| {
+            let getter = f.getter();
+            let checker = f.checker();
+            let ty = &f.ty;
+            let value = f.result_get();
+            quote! {
+                pub fn #getter(&self) -> ::core::option::Option<&#ty> {
+                    #value
+                }
+
+                pub fn #checker(&self) -> bool {
+                    self.#getter().is_some()
+                }
+            }
+        });
+        let missing = self.properties().to_token(|f| {
+            if ! f.is_required() {
+                return quote!();
+            }
+            let id = f.id();
+            let name = f.label();
+            quote! {
+                if self.#id.is_undefined() {
+                    missing.push(#name);
+                }
+            }
+        });
+        let missing = if missing.is_empty() {
+            quote!(#krate::__private::Vec::new())
+        } else {
+            quote! {
+                let mut missing: #krate::__private::Vec<&'static str> = #krate::__private::vec![];
+                #missing
+                missing
+            }
+        };
+        quote! {
+            #getters
+
+            pub fn missing_fields(&self) -> #krate::__private::Vec<&'static str> {
+                #missing
+            }
+        }
+    }
+
+    /// Generate `merge()` (fields set in `other` take precedence) and `or()` (fields set in `self` take precedence) functions
+    pub fn impl_builder_merge(&self) -> TokenStream {
+        let checks = self.properties().to_token(|f| {
+            let checker = f.checker();
+            quote!(other.#checker(),)
+        });
+        let flags = self.properties().to_token(|f| {
+            let flag = format_ident!("is_set_{}", f.ordinal);
+            quote!(#flag,)
+        });
+        let merge = self.properties().to_token(|f| {
+            let id = f.id();
+            let flag = format_ident!("is_set_{}", f.ordinal);
+            quote! {
+                if #flag {
+                    self.#id = other.#id;
+                }
+            }
+        });
+        let merge_cursor = match self.properties().result_cursor() {
+            Some(cursor) => quote!(self.#cursor = self.#cursor.merge(other.#cursor);),
+            None => quote!(),
+        };
+        let (receiver, output) = self.setter_receiver();
+        let or = match self.builder.style {
+            Style::Owned => quote! {
+                other.merge(self)
+            },
+            Style::MutRef => quote! {
+                let mut other = other;
+                other.merge(::core::mem::take(self));
+                *self = other;
+                self
+            },
+        };
+        quote! {
+            pub fn merge(#receiver, other: Self) -> #output {
+                let (#flags) = (#checks);
+                #merge_cursor
+                #merge
+                self
+            }
+
+            pub fn or(#receiver, other: Self) -> #output {
+                #or
+            }
+        }
+    }
+
     /// Generate final `build()` function
     pub fn impl_builder_build(&self) -> TokenStream {
+        let krate = &self.builder.krate;
         let target = &self.builder.target;
         let output = match self.builder.mode {
             Mode::Panic => quote!(#target),
-            Mode::Result => quote!(::core::result::Result<#target, ::std::string::String>),
+            Mode::Result => quote!(::core::result::Result<#target, #krate::__private::String>),
             _ => panic!("Unsupported mode {:?}", self.builder.mode),
         };
-        let content = if self.builder.default.is_enabled() {
-            self.impl_builder_build_from_default()
+        let (receiver, content) = match self.builder.style {
+            Style::Owned => (quote!(self), self.impl_builder_build_content(false)),
+            Style::MutRef => (quote!(&self), self.impl_builder_build_content(true)),
+        };
+        let track_caller = self.track_caller();
+        let build_cloned = if self.builder.build_cloned.is_enabled() {
+            let content = self.impl_builder_build_content(true);
+            quote! {
+                #track_caller
+                pub fn build_cloned(&self) -> #output {
+                    #content
+                }
+            }
         } else {
-            self.impl_builder_build_from_scratch()
+            quote!()
         };
         quote! {
-            pub fn build(self) -> #output {
+            #track_caller
+            pub fn build(#receiver) -> #output {
                 #content
             }
+            #build_cloned
         }
     }
 
-    pub fn impl_builder_build_from_default(&self) -> TokenStream {
+    /// Generate `build()` content (`cloned` when builder is borrowed)
+    pub fn impl_builder_build_content(&self, cloned: bool) -> TokenStream {
+        if self.builder.default.is_enabled() {
+            self.impl_builder_build_from_default(cloned)
+        } else {
+            self.impl_builder_build_from_scratch(cloned)
+        }
+    }
+
+    pub fn impl_builder_build_from_default(&self, cloned: bool) -> TokenStream {
+        let krate = &self.builder.krate;
         let target = &self.builder.target;
-        let overrides = self.properties().result_override();
+        let overrides = self.properties().result_override(cloned);
         let mut result = quote!(built);
         if self.builder.mode == Mode::Result {
             result = quote!(::core::result::Result::Ok(#result))
         }
+        if ! self.properties().groups.is_empty() || self.builder.is_tuple {
+            let check_cursor = self.impl_builder_build_cursor();
+            let check_groups = self.properties().result_group_checks(true);
+            let error = self.impl_builder_build_error();
+            result = quote! {
+                let mut errors: #krate::__private::Vec<#krate::__private::String> = #krate::__private::vec![];
+
+                #check_cursor
+                #check_groups
+
+                if !errors.is_empty() {
+                    #error
+                } else {
+                    #result
+                }
+            };
+        }
         quote! {
             let mut built = <#target as ::core::default::Default>::default();
             #overrides
@@ -164,21 +483,24 @@ impl ResultPanicGenerator {
         }
     }
 
-    pub fn impl_builder_build_from_scratch(&self) -> TokenStream {
+    pub fn impl_builder_build_from_scratch(&self, cloned: bool) -> TokenStream {
+        let krate = &self.builder.krate;
         let target = &self.builder.target;
         let check_fields = self.properties().to_token(|f| {
             if ! f.is_required() {
                 return quote!();
             }
             let id = f.id();
-            let message = format!("Field {} is missing", id);
+            let message = format!("Field {} is missing", f.label());
             quote! {
                 if self.#id.is_undefined() {
                     errors.push(#message.into());
                 }
             }
         });
-        let assign = self.properties().result_build();
+        let check_cursor = self.impl_builder_build_cursor();
+        let check_groups = self.properties().result_group_checks(false);
+        let assign = self.properties().result_build(cloned);
         let create = quote! {
             #target #assign
         };
@@ -187,15 +509,13 @@ impl ResultPanicGenerator {
             Mode::Result => quote!(::core::result::Result::Ok(#create)),
             _ => panic!("Unsupported mode {:?}", self.builder.mode),
         };
-        let error = match self.builder.mode {
-            Mode::Panic => quote!(panic!("{}", errors.join("\n"));),
-            Mode::Result => quote!(::core::result::Result::Err(errors.join("\n"))),
-            _ => panic!("Unsupported mode {:?}", self.builder.mode),
-        };
+        let error = self.impl_builder_build_error();
         quote! {
-            let mut errors: ::std::vec::Vec<::std::string::String> = ::std::vec![];
+            let mut errors: #krate::__private::Vec<#krate::__private::String> = #krate::__private::vec![];
 
             #check_fields
+            #check_cursor
+            #check_groups
 
             if !errors.is_empty() {
                 #error
@@ -205,13 +525,111 @@ impl ResultPanicGenerator {
         }
     }
 
+    /// Generate `From<Target>` implementation for builder with all fields set
+    pub fn impl_builder_from_target(&self) -> TokenStream {
+        let builder_name = &self.builder.ident;
+        let target = &self.builder.target;
+        let fields = self.properties().result_from_target();
+        quote! {
+            impl ::core::convert::From<#target> for #builder_name {
+                fn from(target: #target) -> Self {
+                    #builder_name #fields
+                }
+            }
+        }
+    }
+
+    /// Generate `Clone` implementation for builder (bounded on field types)
+    pub fn impl_builder_clone(&self) -> TokenStream {
+        if self.builder.derives_trait("Clone") {
+            return quote!();
+        }
+        let builder_name = &self.builder.ident;
+        let bounds = self.properties().clone_bounds();
+        let fields = self.properties().result_clone();
+        quote! {
+            impl ::core::clone::Clone for #builder_name where #bounds {
+                fn clone(&self) -> Self {
+                    #builder_name #fields
+                }
+            }
+        }
+    }
+
+    /// Generate `Debug` implementation for builder showing field states (bounded on field types)
+    pub fn impl_builder_debug(&self) -> TokenStream {
+        if self.builder.derives_trait("Debug") {
+            return quote!();
+        }
+        let builder_name = &self.builder.ident;
+        let bounds = self.properties().to_token(|f| {
+            if f.sensitive.is_enabled() {
+                return quote!();
+            }
+            let ty = &f.ty;
+            quote!(for<'__macon> #ty: ::core::fmt::Debug,)
+        });
+        let fields = self.properties().to_token(|f| f.debug_field());
+        let content = self.debug_fmt(fields);
+        quote! {
+            impl ::core::fmt::Debug for #builder_name where #bounds {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    #content
+                }
+            }
+        }
+    }
+
+    /// Generate `::macon::Builder` trait implementation
+    pub fn impl_builder_trait(&self) -> TokenStream {
+        let krate = &self.builder.krate;
+        let builder_name = &self.builder.ident;
+        let target = &self.builder.target;
+        let output = match self.builder.mode {
+            Mode::Panic => quote!(#target),
+            Mode::Result => quote!(::core::result::Result<#target, #krate::__private::String>),
+            _ => panic!("Unsupported mode {:?}", self.builder.mode),
+        };
+        // Implemented for reference with `MutRef` style, to keep inherent `build(&self)` resolved first (an owned implementation
+        // would be picked on `&mut Self` returned by setters, moving builder out of reference)
+        let (generics, implementor) = match self.builder.style {
+            Style::Owned => (quote!(), quote!(#builder_name)),
+            Style::MutRef => (quote!(<'__macon>), quote!(&'__macon #builder_name)),
+        };
+        let track_caller = self.track_caller();
+        quote! {
+            impl #generics #krate::Builder for #implementor {
+                type Target = #target;
+                type Output = #output;
+                #track_caller
+                fn build(self) -> Self::Output {
+                    #builder_name::build(self)
+                }
+            }
+        }
+    }
+
+    /// Generate failure from collected `errors`
+    pub fn impl_builder_build_error(&self) -> TokenStream {
+        match self.builder.mode {
+            Mode::Panic => {
+                let header = self.builder.panic_header();
+                quote!(panic!("{}\n{}", #header, errors.join("\n"));)
+            },
+            Mode::Result => quote!(::core::result::Result::Err(errors.join("\n"))),
+            _ => panic!("Unsupported mode {:?}", self.builder.mode),
+        }
+    }
+
     pub fn impl_builder_from(&self) -> TokenStream {
+        let krate = &self.builder.krate;
         let builder_name = &self.builder.ident;
         let target = &self.builder.target;
         match self.builder.mode {
             Mode::Panic =>
                 quote! {
                     impl ::core::convert::From<#builder_name> for #target {
+                        #[track_caller]
                         fn from(builder: #builder_name) -> Self {
                             builder.build()
                         }
@@ -220,7 +638,7 @@ impl ResultPanicGenerator {
             Mode::Result =>
                 quote! {
                     impl ::core::convert::TryFrom<#builder_name> for #target {
-                        type Error = ::std::string::String;
+                        type Error = #krate::__private::String;
                         fn try_from(builder: #builder_name) -> ::core::result::Result<Self, Self::Error> {
                             builder.build()
                         }
@@ -236,19 +654,35 @@ impl Generator for ResultPanicGenerator {
         &self.builder
     }
 
+    fn builder_complete(&self) -> TokenStream {
+        self.builder.ident.to_token_stream()
+    }
+
     /// Generate all declarations:
     ///
     /// * `impl` block to add `builder()` function to target struct ([`Self::impl_target()`])
+    /// * `impl` block to convert target struct back into builder ([`Self::impl_target_into_builder()`])
+    /// * `Buildable` implementation for target struct ([`Self::impl_target_buildable()`])
+    /// * `impl` block to add `with_<field>()` functions to target struct ([`Self::impl_target_withers()`])
     /// * builder struct ([`Self::struct_builder()`])
     /// * `impl` block for generated builder struct ([`Self::impl_builder()`])
+    /// * patch struct ([`Self::patch()`])
     fn all(&self) -> TokenStream {
         let impl_target = self.impl_target();
+        let impl_target_into_builder = self.impl_target_into_builder();
+        let impl_target_buildable = self.impl_target_buildable();
+        let impl_target_withers = self.impl_target_withers();
+        let patch = self.patch();
         let struct_builder = self.struct_builder();
         let impl_builder = self.impl_builder();
         quote! {
             #impl_target
+            #impl_target_into_builder
+            #impl_target_buildable
+            #impl_target_withers
             #struct_builder
             #impl_builder
+            #patch
         }
     }
 }
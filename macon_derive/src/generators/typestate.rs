@@ -24,19 +24,59 @@ impl Generator for StateGenerator {
         &self.builder
     }
 
+    fn builder_complete(&self) -> TokenStream {
+        let builder_name = &self.builder.ident;
+        let complete_state = self.properties().typestate_state_complete();
+        quote!(#builder_name<#complete_state>)
+    }
+
     /// Generate all declarations:
     ///
     /// * `impl` block to add `builder()` function to target struct ([`Self::impl_target`])
+    /// * `impl` block to convert target struct back into builder ([`Self::impl_target_into_builder()`], see [`Self::is_complete_buildable()`])
+    /// * `Buildable` implementation for target struct ([`Self::impl_target_buildable()`])
+    /// * `impl` block to add `with_<field>()` functions to target struct ([`Self::impl_target_withers()`])
     /// * builder struct ([`Self::struct_builder()`])
     /// * `impl` block for generated builder struct ([`Self::impl_builder()`])
+    /// * marker traits for field groups ([`Self::groups()`])
+    /// * marker traits for field typestates ([`Self::field_markers()`])
+    /// * field state trait and checkers ([`Self::field_state()`])
+    /// * builder state type aliases ([`Self::type_aliases()`])
+    /// * builder traits per field ([`Self::field_traits()`])
+    /// * runtime-checked builder ([`Self::dynamic()`])
+    /// * patch struct ([`Self::patch()`])
     fn all(&self) -> TokenStream {
         let impl_target = self.impl_target();
+        let impl_target_into_builder = if self.is_complete_buildable() {
+            self.impl_target_into_builder()
+        } else {
+            quote!()
+        };
+        let impl_target_buildable = self.impl_target_buildable();
+        let impl_target_withers = self.impl_target_withers();
+        let patch = self.patch();
         let struct_builder = self.struct_builder();
         let impl_builder = self.impl_builder();
+        let groups = self.groups();
+        let field_markers = self.field_markers();
+        let field_state = self.field_state();
+        let type_aliases = self.type_aliases();
+        let field_traits = self.field_traits();
+        let dynamic = self.dynamic();
         quote! {
             #impl_target
+            #impl_target_into_builder
+            #impl_target_buildable
+            #impl_target_withers
             #struct_builder
             #impl_builder
+            #groups
+            #field_markers
+            #field_state
+            #type_aliases
+            #field_traits
+            #dynamic
+            #patch
         }
     }
 }
@@ -53,191 +93,661 @@ impl StateGenerator {
         } else {
             quote!()
         };
+        let attrs = self.builder.struct_attrs();
         quote! {
-            #[derive(Default,)]
+            #attrs
             #vis struct #builder_name<#typestate_default>#fields #delim
         }
     }
 
     pub fn impl_builder(&self) -> TokenStream {
-        // let impl_builder_default = self.impl_builder_default();
-        let setters = self.properties().to_token(|f| self.impl_builder_setter(f));
+        let default_impl = self.impl_builder_default();
+        let setters = self.impl_builder_setters();
         let build = self.impl_builder_build();
         let from_impl = self.impl_builder_from();
+        let clone_impl = self.impl_builder_clone();
+        let debug_impl = self.impl_builder_debug();
+        let trait_impl = self.impl_builder_trait();
+        let from_target_impl = if self.is_complete_buildable() {
+            self.impl_builder_from_target()
+        } else {
+            quote!()
+        };
         quote! {
-            // #impl_builder_default
+            #default_impl
             #setters
             #build
             #from_impl
+            #clone_impl
+            #debug_impl
+            #trait_impl
+            #from_target_impl
         }
     }
 
-    pub fn impl_builder_setter(&self, field: &Property) -> TokenStream {
+    /// Generate `Default` implementation for builder with all fields unset
+    pub fn impl_builder_default(&self) -> TokenStream {
         let builder_name = &self.builder.ident;
-        let impl_state = self.properties().typestate_setter_impl(field);
-        let struct_state_from = self.properties().typestate_state(field, false, false);
-        let struct_state_to = self.properties().typestate_state(field, false, true);
-
+        let init = self.properties().typestate_init();
+        quote! {
+            impl ::core::default::Default for #builder_name {
+                fn default() -> Self {
+                    #builder_name #init
+                }
+            }
+        }
+    }
 
-        let ident = &field.ident;
-        let into_type = field.ty_into();
-        let argtype = if ! field.into.is_disabled() {
-            field.typevar().to_token_stream()
-        } else {
-            field.ty.to_token_stream()
-        };
-        let generic = if ! field.into.is_disabled() {
-            quote!(<#argtype: ::core::convert::Into<#into_type>>)
-        } else {
-            quote!()
-        };
+    /// Check if builder with all fields set can be built (i.e. no `exactly_one` group with many members)
+    pub fn is_complete_buildable(&self) -> bool {
+        ! self.properties()
+            .groups
+            .iter()
+            .any(|g| g.kind.is_exclusive() && g.kind.is_required() && g.members.len() > 1)
+    }
 
-        let setter_standard = {
-            let setter_standard = field.setter();
-            let fields_standard = self.properties().typestate_assign(field, Setter::Standard);
-            quote! {
-                pub fn #setter_standard #generic(self, #ident: #argtype) -> #builder_name<#struct_state_to> {
-                    #builder_name #fields_standard
+    /// Generate `From<Target>` implementation for builder with all fields set
+    pub fn impl_builder_from_target(&self) -> TokenStream {
+        let target = &self.builder.target;
+        let builder_name = &self.builder.ident;
+        let builder_complete = self.builder_complete();
+        let fields = self.properties().typestate_from_target();
+        quote! {
+            impl ::core::convert::From<#target> for #builder_complete {
+                fn from(target: #target) -> Self {
+                    #builder_name #fields
                 }
             }
-        };
+        }
+    }
 
-        let setter_option = if field.option.is_enabled() {
-            let setter_none = field.setter_none();
-            let fields_none = self.properties().typestate_assign(field, Setter::None);
-            let setter_optional = field.setter_optional();
-            let generic = if ! field.into.is_disabled() {
-                quote!(<#argtype: ::core::convert::Into<#into_type>>)
+    /// Generate marker traits implemented by counts of set members satisfying group constraint
+    pub fn groups(&self) -> TokenStream {
+        let vis = &self.builder.vis;
+        let builder_name = &self.builder.ident;
+        self.properties()
+            .groups
+            .iter()
+            .filter(|g| g.kind.is_required())
+            .map(|group| {
+                let trait_ident = group.trait_ident(builder_name);
+                let counts = group.typestate_counts(&self.builder.krate);
+                let message = group.message(self.properties());
+                quote! {
+                    #[doc(hidden)]
+                    #[diagnostic::on_unimplemented(message = #message)]
+                    #vis trait #trait_ident {}
+                    #(impl #trait_ident for #counts {})*
+                }
+            })
+            .collect()
+    }
+
+    /// Generate field getters, checkers (from `::macon::FieldState` for tracked fields) and `missing_fields()` function
+    pub fn field_state(&self) -> TokenStream {
+        let krate = &self.builder.krate;
+        let builder_name = &self.builder.ident;
+        let impl_state = self.properties().to_token(|f| if f.is_tracked() {
+            let typevar = f.typevar();
+            quote!(#typevar: #krate::FieldState,)
+        } else {
+            quote!()
+        });
+        let struct_state = self.properties().to_token(|f| f.typestate(Some(true)));
+        let checkers = self.properties().to_token(|f| {
+            let getter = f.getter();
+            let checker = f.checker();
+            let ty = &f.ty;
+            let value = f.result_get();
+            // Untracked fields state is only known at runtime
+            let checker = if f.is_tracked() {
+                let typevar = f.typevar();
+                quote! {
+                    pub const fn #checker(&self) -> bool {
+                        #typevar::IS_SET
+                    }
+                }
             } else {
-                quote!()
+                quote! {
+                    pub fn #checker(&self) -> bool {
+                        self.#getter().is_some()
+                    }
+                }
             };
-            let fields_optional = self.properties().typestate_assign(field, Setter::Optional);
             quote! {
-                pub fn #setter_none(self) -> #builder_name<#struct_state_to> {
-                    #builder_name #fields_none
+                pub fn #getter(&self) -> ::core::option::Option<&#ty> {
+                    #value
                 }
 
-                pub fn #setter_optional #generic(self, #ident: ::core::option::Option<#argtype>) -> #builder_name<#struct_state_to> {
-                    #builder_name #fields_optional
+                #checker
+            }
+        });
+        let missing = self.properties().to_token(|f| {
+            if ! f.is_required() {
+                return quote!();
+            }
+            let typevar = f.typevar();
+            let name = f.label();
+            quote! {
+                if ! #typevar::IS_SET {
+                    missing.push(#name);
                 }
             }
+        });
+        let missing = if missing.is_empty() {
+            quote!(#krate::__private::Vec::new())
         } else {
-            quote!()
-        };
-        let setter_keep = if field.struct_default.is_enabled() {
-            let setter_keep = field.setter_keep();
-            let fields_keep = self.properties().typestate_assign(field, Setter::Keep);
             quote! {
-                pub fn #setter_keep(self) -> #builder_name<#struct_state_to> {
-                    #builder_name #fields_keep
+                let mut missing: #krate::__private::Vec<&'static str> = #krate::__private::vec![];
+                #missing
+                missing
+            }
+        };
+        quote! {
+            impl<#impl_state> #builder_name<#struct_state> {
+                #checkers
+
+                #krate::__alloc! {
+                    pub fn missing_fields(&self) -> #krate::__private::Vec<&'static str> {
+                        #missing
+                    }
                 }
             }
-        } else {
-            quote!()
+        }
+    }
+
+    /// Generate runtime-checked builder (`Result` mode) and `into_dynamic()` function converting into it (only when `dynamic` is enabled)
+    pub fn dynamic(&self) -> TokenStream {
+        let dynamic = match self.builder.dynamic_builder() {
+            Some(dynamic) => result_panic::ResultPanicGenerator::new(dynamic),
+            None => return quote!(),
         };
-        let setter_default = if field.default.is_enabled() {
-            let setter_default = field.setter_default();
-            let fields_default = self.properties().typestate_assign(field, Setter::Default);
+        let builder_name = &self.builder.ident;
+        let dynamic_name = &dynamic.builder().ident;
+        let typevars = self.properties().to_token(|f| f.typestate(Some(true)));
+        let fields = self.properties().typestate_dynamic();
+        let body = if self.builder.konst.is_enabled() {
+            quote! {
+                let builder = #dynamic_name #fields;
+                let _ = ::core::mem::ManuallyDrop::new(self);
+                builder
+            }
+        } else {
             quote! {
-                pub fn #setter_default(self) -> #builder_name<#struct_state_to> {
-                    #builder_name #fields_default
+                #dynamic_name #fields
+            }
+        };
+        let struct_builder = dynamic.struct_builder();
+        let impl_builder = dynamic.impl_builder();
+        quote! {
+            impl<#typevars> #builder_name<#typevars> {
+                pub fn into_dynamic(self) -> #dynamic_name {
+                    #body
                 }
             }
+
+            #struct_builder
+            #impl_builder
+        }
+    }
+
+    /// Generate type aliases naming builder empty state (`<Builder>Empty`) and, when buildable, complete state (`<Builder>Complete`)
+    pub fn type_aliases(&self) -> TokenStream {
+        let vis = &self.builder.vis;
+        let builder_name = &self.builder.ident;
+        let empty_ident = format_ident!("{}Empty", builder_name);
+        let empty_doc = format!("[`{}`] with no field set", builder_name);
+        let complete = if self.is_complete_buildable() {
+            let complete_ident = format_ident!("{}Complete", builder_name);
+            let complete_doc = format!("[`{}`] with all fields set", builder_name);
+            let builder_complete = self.builder_complete();
+            quote! {
+                #[doc = #complete_doc]
+                #vis type #complete_ident = #builder_complete;
+            }
         } else {
             quote!()
         };
-        let mut impl_setter = quote! {
-            impl<#impl_state> #builder_name<#struct_state_from> {
-                #setter_standard
-                #setter_option
-                #setter_keep
-                #setter_default
+        quote! {
+            #[doc = #empty_doc]
+            #vis type #empty_ident = #builder_name;
+            #complete
+        }
+    }
+
+    /// Generate traits per tracked field implemented by builder typestates:
+    ///
+    /// * `<Builder>Has<Field>` when field is set (bounds `build()` for required fields, with readable diagnostics)
+    /// * `<Builder>Needs<Field>` when field can be set, forwarding to its (bounded) value setter (not for `ordered` named builders)
+    ///
+    /// Typestates are only `Unset`/`Set` markers, so field types never appear in implementations (but setter argument).
+    pub fn field_traits(&self) -> TokenStream {
+        let vis = &self.builder.vis;
+        let builder_name = &self.builder.ident;
+        let typevars = self.properties().to_token(|f| f.typestate(Some(true)));
+        let has_needs = ! self.builder.ordered.is_enabled() || self.builder.is_tuple;
+        self.properties().to_token(|f| {
+            if ! f.is_tracked() {
+                return quote!();
             }
-        };
-        if self.builder.is_tuple {
-            let struct_state_from_ordered = self.properties().typestate_state(field, true, false);
-            let struct_state_to_ordered = self.properties().typestate_state(field, true, true);
-            let typevar = field.typevar();
-            let into_type = field.ty_into();
-            let ident = &field.ident;
-            let setter_standard_ordered = {
-                let setter_standard = field.setter();
-                quote! {
-                    pub fn set<#typevar: ::core::convert::Into<#into_type>>(self, #ident: #typevar) -> #builder_name<#struct_state_to_ordered> {
-                        self.#setter_standard(#ident)
-                    }
-                }
-            };
-            let setter_option_ordered = if field.option.is_enabled() {
-                let setter_none = field.setter_none();
-                let setter_optional = field.setter_optional();
+            let camel = format_ident!("{}", f.typestate_camel_name());
+            let has_trait = f.typestate_has_trait(builder_name);
+            let has_doc = format!("[`{}`] typestates where field `{}` is set", builder_name, f.label());
+            let (has_message, has_label) = self.has_diagnostic(f);
+            let has_typevars = self.properties().to_token(|p| p.typestate_setter_impl(f));
+            let has_state = self.properties().to_token(|p| if p.name == f.name {
+                p.typestate(Some(false))
+            } else {
+                p.typestate(Some(true))
+            });
+            let needs = if has_needs {
+                let needs_trait = format_ident!("{}Needs{}", builder_name, camel);
+                let needs_doc = format!("[`{}`] typestates where field `{}` can be set", builder_name, f.label());
+                let with = format_ident!("With{}", camel);
+                let setter = f.setter();
+                let ident = &f.ident;
+                let (generic, argtype) = self.setter_argument(f);
+                let bounds = self.setter_bounds(f);
+                let struct_state_to = self.properties().typestate_state(f, false, true);
                 quote! {
-                    pub fn none(self) -> #builder_name<#struct_state_to_ordered> {
-                        self.#setter_none()
+                    #[doc = #needs_doc]
+                    #vis trait #needs_trait: ::core::marker::Sized {
+                        type #with: #has_trait;
+                        fn #setter #generic(self, #ident: #argtype) -> Self::#with;
                     }
-                    pub fn optional<#typevar: ::core::convert::Into<#into_type>>(self, #ident: ::core::option::Option<#typevar>) -> #builder_name<#struct_state_to_ordered> {
-                        self.#setter_optional(#ident)
+
+                    impl<#typevars> #needs_trait for #builder_name<#typevars> where #bounds {
+                        type #with = #builder_name<#struct_state_to>;
+                        fn #setter #generic(self, #ident: #argtype) -> Self::#with {
+                            #builder_name::#setter(self, #ident)
+                        }
                     }
                 }
             } else {
                 quote!()
             };
-            let setter_keep_ordered = if field.struct_default.is_enabled() {
-                let setter_keep = field.setter_keep();
+            quote! {
+                #[doc = #has_doc]
+                #[diagnostic::on_unimplemented(message = #has_message, label = #has_label)]
+                #vis trait #has_trait {}
+
+                impl<#has_typevars> #has_trait for #builder_name<#has_state> {}
+
+                #needs
+            }
+        })
+    }
+
+    /// Generate marker traits per tracked field, implemented by unset typestate (bounds setters), with readable diagnostics
+    /// when not implemented
+    pub fn field_markers(&self) -> TokenStream {
+        let krate = &self.builder.krate;
+        let vis = &self.builder.vis;
+        let builder_name = &self.builder.ident;
+        self.properties().to_token(|f| {
+            if ! f.is_tracked() {
+                return quote!();
+            }
+            let id = f.label();
+            let unset_trait = f.typestate_unset_trait(builder_name);
+            let unset_message = format!("field `{}` was already set", id);
+            let unset_label = format!("`{}` can only be set once", id);
+            let unset_note = match self.properties().get_group(f) {
+                Some(group) if group.kind.is_exclusive() => {
+                    let note = group.message(self.properties());
+                    quote!(, note = #note)
+                },
+                _ => quote!(),
+            };
+            quote! {
+                #[doc(hidden)]
+                #[diagnostic::on_unimplemented(message = #unset_message, label = #unset_label #unset_note)]
+                #vis trait #unset_trait {}
+
+                impl #unset_trait for #krate::Unset {}
+            }
+        })
+    }
+
+    pub fn group_where_clause(&self) -> TokenStream {
+        let bounds = self.properties().typestate_group_bounds(&self.builder.ident);
+        if bounds.is_empty() {
+            quote!()
+        } else {
+            quote!(where #bounds)
+        }
+    }
+
+    /// `const` keyword for setters and `build()` when enabled
+    pub fn constness(&self) -> TokenStream {
+        if self.builder.konst.is_enabled() {
+            quote!(const)
+        } else {
+            quote!()
+        }
+    }
+
+    /// Generate `impl` block with all setters (bounded on unset typestates) and typestate transition function
+    ///
+    /// When `ordered` is enabled for named struct, setters are declared in one `impl` block per field, only matching
+    /// typestate where previous fields are set.
+    pub fn impl_builder_setters(&self) -> TokenStream {
+        let builder_name = &self.builder.ident;
+        let typevars = self.properties().to_token(|f| f.typestate(Some(true)));
+        let transition = self.impl_builder_transition();
+        if self.builder.ordered.is_enabled() && ! self.builder.is_tuple {
+            let ordered = self.properties().to_token(|f| {
+                let struct_state_from = self.properties().typestate_state(f, true, false);
+                let setters = self.impl_builder_setter(f, true);
                 quote! {
-                    pub fn keep(self) -> #builder_name<#struct_state_to_ordered> {
-                        self.#setter_keep()
+                    impl #builder_name<#struct_state_from> {
+                        #setters
                     }
                 }
+            });
+            return quote! {
+                impl<#typevars> #builder_name<#typevars> {
+                    #transition
+                }
+                #ordered
+            };
+        }
+        let setters = self.properties().to_token(|f| self.impl_builder_setter(f, false));
+        let ordered = self.properties().to_token(|f| self.impl_builder_setter_ordered(f));
+        quote! {
+            impl<#typevars> #builder_name<#typevars> {
+                #setters
+                #transition
+            }
+            #ordered
+        }
+    }
+
+    /// Generate function moving fields storage into builder with another typestate
+    ///
+    /// Typestate is only carried by builder typevars, so setters assign their field storage then change typestate.
+    pub fn impl_builder_transition(&self) -> TokenStream {
+        if ! self.properties().iter().any(|f| f.is_tracked()) {
+            return quote!();
+        }
+        let builder_name = &self.builder.ident;
+        let constness = self.constness();
+        let typevars = self.properties().to_token(|f| if f.is_tracked() {
+            let typevar = format_ident!("__{}", f.typevar());
+            quote!(#typevar,)
+        } else {
+            quote!()
+        });
+        let fields = self.properties().typestate_transition();
+        let body = if self.builder.konst.is_enabled() {
+            quote! {
+                let builder = #builder_name #fields;
+                let _ = ::core::mem::ManuallyDrop::new(self);
+                builder
+            }
+        } else {
+            quote! {
+                #builder_name #fields
+            }
+        };
+        quote! {
+            #constness fn __typestate<#typevars>(self) -> #builder_name<#typevars> {
+                #body
+            }
+        }
+    }
+
+    /// Diagnostic message and label of `<Builder>Has<Field>` trait, reported when building without a required field
+    pub fn has_diagnostic(&self, field: &Property) -> (String, String) {
+        let id = field.label();
+        let target = self.builder.target.to_string();
+        let message = if field.explicit.is_enabled() {
+            format!("field `{}` of `{}` must be set (or explicitly unset with `{}()`) before calling build()", id, target, field.setter_none())
+        } else if field.is_required() {
+            format!("field `{}` of `{}` must be set before calling build()", id, target)
+        } else {
+            format!("field `{}` of `{}` is not set", id, target)
+        };
+        (message, format!("`{}` is not set", id))
+    }
+
+    /// Bounds on typestates for setters of given field to be available (field itself and exclusive group members unset)
+    pub fn setter_bounds(&self, field: &Property) -> TokenStream {
+        let builder_name = &self.builder.ident;
+        self.properties().to_token(|p| {
+            if ! p.is_tracked() {
+                quote!()
+            } else if (p.name == field.name && ! p.overwrite.is_enabled()) || self.properties().is_exclusive(p, field) {
+                let typevar = p.typevar();
+                let unset_trait = p.typestate_unset_trait(builder_name);
+                quote!(#typevar: #unset_trait,)
             } else {
                 quote!()
-            };
-            let setter_default_ordered = if field.default.is_enabled() {
-                let setter_default = field.setter_default();
-                quote! {
-                    pub fn default(self) -> #builder_name<#struct_state_to_ordered> {
-                        self.#setter_default()
+            }
+        })
+    }
+
+    /// Setter value argument generic declaration and type (generic over [`Into`] unless disabled)
+    pub fn setter_argument(&self, field: &Property) -> (TokenStream, TokenStream) {
+        let into_type = field.ty_into();
+        if field.into.is_disabled() {
+            (quote!(), into_type.to_token_stream())
+        } else {
+            (quote!(<__VALUE: ::core::convert::Into<#into_type>>), quote!(__VALUE))
+        }
+    }
+
+    /// Generate setters of given field (`ordered` ones are declared for a single typestate, so unbounded)
+    pub fn impl_builder_setter(&self, field: &Property, ordered: bool) -> TokenStream {
+        let builder_name = &self.builder.ident;
+        let bounds = if ordered {
+            quote!()
+        } else {
+            self.setter_bounds(field)
+        };
+        let struct_state_to = self.properties().typestate_state(field, ordered, true);
+        let attrs = field.setter_attrs();
+        let constness = self.constness();
+
+        let ident = &field.ident;
+        let (generic, argtype) = self.setter_argument(field);
+        let setter = |name: &syn::Ident, generic: &TokenStream, args: TokenStream, setter: Setter| {
+            let assign = field.typestate_assign(setter);
+            // Untracked field setters keep typestate (so stay available once set)
+            if ! field.is_tracked() {
+                return quote! {
+                    #attrs
+                    pub #constness fn #name #generic(mut self, #args) -> Self {
+                        #assign
+                        self
                     }
+                };
+            }
+            quote! {
+                #attrs
+                pub #constness fn #name #generic(mut self, #args) -> #builder_name<#struct_state_to> where #bounds {
+                    #assign
+                    self.__typestate()
+                }
+            }
+        };
+
+        let setter_standard = setter(&field.setter(), &generic, quote!(#ident: #argtype), Setter::Standard);
+
+        // Group members are left unset (so not counted by group constraint) by `None` and `Default` setters, keeping typestate
+        let group = self.properties().get_group(field);
+        let setter_unset = |name: &syn::Ident, setter: Setter| {
+            let assign = field.typestate_assign(setter);
+            let bounds = if field.overwrite.is_enabled() {
+                quote!()
+            } else {
+                let typevar = field.typevar();
+                let unset_trait = field.typestate_unset_trait(builder_name);
+                quote!(#typevar: #unset_trait,)
+            };
+            quote! {
+                #attrs
+                pub #constness fn #name(mut self) -> Self where #bounds {
+                    #assign
+                    self
                 }
+            }
+        };
+
+        let setter_option = if field.option.is_enabled() {
+            let setter_none = if group.is_some() {
+                setter_unset(&field.setter_none(), Setter::None)
             } else {
+                setter(&field.setter_none(), &quote!(), quote!(), Setter::None)
+            };
+            // Optional value is only known at runtime, so it's counted as set (only valid for `at_most_one` group)
+            let setter_optional = if group.map(|g| g.kind.is_required()).unwrap_or(false) {
                 quote!()
+            } else {
+                setter(&field.setter_optional(), &generic, quote!(#ident: ::core::option::Option<#argtype>), Setter::Optional)
             };
-            impl_setter = quote! {
-                #impl_setter
-
-                impl #builder_name<#struct_state_from_ordered> {
-                    #setter_standard_ordered
-                    #setter_option_ordered
-                    #setter_keep_ordered
-                    #setter_default_ordered
+            quote! {
+                #setter_none
+                #setter_optional
+            }
+        } else {
+            quote!()
+        };
+        let setter_keep = if field.struct_default.is_enabled() {
+            setter(&field.setter_keep(), &quote!(), quote!(), Setter::Keep)
+        } else {
+            quote!()
+        };
+        let setter_default = if ! field.default.is_enabled() {
+            quote!()
+        } else if group.is_some() {
+            setter_unset(&field.setter_default(), Setter::Default)
+        } else {
+            setter(&field.setter_default(), &quote!(), quote!(), Setter::Default)
+        };
+        quote! {
+            #setter_standard
+            #setter_option
+            #setter_keep
+            #setter_default
+        }
+    }
+
+    /// Generate `impl` block with positional setters (`set()`, `none()`, ...) for tuple builder
+    pub fn impl_builder_setter_ordered(&self, field: &Property) -> TokenStream {
+        if ! self.builder.is_tuple || field.group.is_some() {
+            return quote!();
+        }
+        let builder_name = &self.builder.ident;
+        let struct_state_from_ordered = self.properties().typestate_state(field, true, false);
+        let struct_state_to_ordered = self.properties().typestate_state(field, true, true);
+        let attrs = field.setter_attrs();
+        let constness = self.constness();
+
+        let ident = &field.ident;
+        let (generic, argtype) = self.setter_argument(field);
+        let setter_standard_ordered = {
+            let setter_standard = field.setter();
+            quote! {
+                #attrs
+                pub #constness fn set #generic(self, #ident: #argtype) -> #builder_name<#struct_state_to_ordered> {
+                    self.#setter_standard(#ident)
                 }
             }
+        };
+        let setter_option_ordered = if field.option.is_enabled() {
+            let setter_none = field.setter_none();
+            let setter_optional = field.setter_optional();
+            quote! {
+                #attrs
+                pub #constness fn none(self) -> #builder_name<#struct_state_to_ordered> {
+                    self.#setter_none()
+                }
+                #attrs
+                pub #constness fn optional #generic(self, #ident: ::core::option::Option<#argtype>) -> #builder_name<#struct_state_to_ordered> {
+                    self.#setter_optional(#ident)
+                }
+            }
+        } else {
+            quote!()
+        };
+        let setter_keep_ordered = if field.struct_default.is_enabled() {
+            let setter_keep = field.setter_keep();
+            quote! {
+                #attrs
+                pub #constness fn keep(self) -> #builder_name<#struct_state_to_ordered> {
+                    self.#setter_keep()
+                }
+            }
+        } else {
+            quote!()
+        };
+        let setter_default_ordered = if field.default.is_enabled() {
+            let setter_default = field.setter_default();
+            quote! {
+                #attrs
+                pub #constness fn default(self) -> #builder_name<#struct_state_to_ordered> {
+                    self.#setter_default()
+                }
+            }
+        } else {
+            quote!()
+        };
+        quote! {
+            impl #builder_name<#struct_state_from_ordered> {
+                #setter_standard_ordered
+                #setter_option_ordered
+                #setter_keep_ordered
+                #setter_default_ordered
+            }
         }
-        impl_setter
     }
 
     pub fn impl_builder_build(&self) -> TokenStream {
-        let option_typevars = self.properties().typestate_optional_marker();
         let builder_name = &self.builder.ident;
-        let final_state = self.properties().typestate_state_final();
+        let typevars = self.properties().to_token(|f| f.typestate(Some(true)));
         let target = &self.builder.target;
-        let content = if self.builder.default.is_enabled() {
-            self.impl_builder_build_from_default()
+        let required_bounds = self.properties().typestate_required_bounds(builder_name);
+        let group_bounds = self.properties().typestate_group_bounds(builder_name);
+        let where_clause = if required_bounds.is_empty() && group_bounds.is_empty() {
+            quote!()
+        } else {
+            quote!(where #required_bounds #group_bounds)
+        };
+        let content = self.impl_builder_build_content(false);
+        let constness = self.constness();
+        let build_cloned = if self.builder.build_cloned.is_enabled() {
+            let content = self.impl_builder_build_content(true);
+            quote! {
+                pub fn build_cloned(&self) -> #target #where_clause {
+                    #content
+                }
+            }
         } else {
-            self.impl_builder_build_from_scratch()
+            quote!()
         };
         quote! {
-            impl<#option_typevars> #builder_name<#final_state> {
-                pub fn build(self) -> #target {
+            impl<#typevars> #builder_name<#typevars> {
+                pub #constness fn build(self) -> #target #where_clause {
                     #content
                 }
+                #build_cloned
             }
         }
     }
 
-    pub fn impl_builder_build_from_default(&self) -> TokenStream {
+    /// Generate `build()` content (`cloned` when builder is borrowed)
+    pub fn impl_builder_build_content(&self, cloned: bool) -> TokenStream {
+        if self.builder.default.is_enabled() {
+            self.impl_builder_build_from_default(cloned)
+        } else {
+            self.impl_builder_build_from_scratch(cloned)
+        }
+    }
+
+    pub fn impl_builder_build_from_default(&self, cloned: bool) -> TokenStream {
         let target = &self.builder.target;
-        let overrides = self.properties().typestate_override();
+        let overrides = self.properties().typestate_override(cloned);
         quote! {
             let mut built = <#target as ::core::default::Default>::default();
             #overrides
@@ -245,11 +755,82 @@ impl StateGenerator {
         }
     }
 
-    pub fn impl_builder_build_from_scratch(&self) -> TokenStream {
+    pub fn impl_builder_build_from_scratch(&self, cloned: bool) -> TokenStream {
         let target = &self.builder.target;
-        let assign = self.properties().typestate_build();
+        let assign = self.properties().typestate_build(cloned);
+        if self.builder.konst.is_enabled() && ! cloned {
+            quote! {
+                let built = #target #assign;
+                let _ = ::core::mem::ManuallyDrop::new(self);
+                built
+            }
+        } else {
+            quote! {
+                #target #assign
+            }
+        }
+    }
+
+    /// Generate `Clone` implementation for builder (bounded on field types)
+    pub fn impl_builder_clone(&self) -> TokenStream {
+        if self.builder.derives_trait("Clone") {
+            return quote!();
+        }
+        let builder_name = &self.builder.ident;
+        let typevars = self.properties().to_token(|f| f.typestate(Some(true)));
+        let bounds = self.properties().clone_bounds();
+        let fields = self.properties().typestate_clone();
         quote! {
-            #target #assign
+            impl<#typevars> ::core::clone::Clone for #builder_name<#typevars> where #bounds {
+                fn clone(&self) -> Self {
+                    #builder_name #fields
+                }
+            }
+        }
+    }
+
+    /// Generate `Debug` implementation for builder showing field states (bounded on field types)
+    pub fn impl_builder_debug(&self) -> TokenStream {
+        if self.builder.derives_trait("Debug") {
+            return quote!();
+        }
+        let builder_name = &self.builder.ident;
+        let typevars = self.properties().to_token(|f| f.typestate(Some(true)));
+        let bounds = self.properties().to_token(|f| {
+            let ty = &f.ty;
+            if f.sensitive.is_enabled() {
+                quote!()
+            } else {
+                quote!(for<'__macon> #ty: ::core::fmt::Debug,)
+            }
+        });
+        let fields = self.properties().to_token(|f| f.debug_field());
+        let content = self.debug_fmt(fields);
+        quote! {
+            impl<#typevars> ::core::fmt::Debug for #builder_name<#typevars> where #bounds {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    #content
+                }
+            }
+        }
+    }
+
+    /// Generate `::macon::Builder` trait implementation (for buildable states)
+    pub fn impl_builder_trait(&self) -> TokenStream {
+        let krate = &self.builder.krate;
+        let builder_name = &self.builder.ident;
+        let target = &self.builder.target;
+        let final_state = self.properties().typestate_state_final();
+        let option_typevars = self.properties().typestate_optional_marker();
+        let where_clause = self.group_where_clause();
+        quote! {
+            impl<#option_typevars> #krate::Builder for #builder_name<#final_state> #where_clause {
+                type Target = #target;
+                type Output = #target;
+                fn build(self) -> Self::Output {
+                    #builder_name::<#final_state>::build(self)
+                }
+            }
         }
     }
 
@@ -258,8 +839,9 @@ impl StateGenerator {
         let target = &self.builder.target;
         let final_state = self.properties().typestate_state_final();
         let option_typevars: TokenStream = self.properties().typestate_optional_marker();
+        let where_clause = self.group_where_clause();
         quote! {
-            impl<#option_typevars> ::core::convert::From<#builder_name<#final_state>> for #target {
+            impl<#option_typevars> ::core::convert::From<#builder_name<#final_state>> for #target #where_clause {
                 fn from(builder: #builder_name<#final_state>) -> Self {
                     builder.build()
                 }
@@ -10,8 +10,9 @@ use crate::attributes::{
 use std::borrow::Cow;
 use proc_macro2::{
     Delimiter,
-    Group,
+    Group as TokenGroup,
     Literal,
+    Span,
     TokenStream,
 };
 use quote::{
@@ -27,13 +28,15 @@ use syn::{
     Fields,
     GenericArgument,
     Ident,
+    Meta,
+    Path,
     PathArguments,
     Result,
     Type,
     Visibility,
 };
 
-#[derive(Debug)]
+#[derive(Clone,Debug)]
 pub struct Builder {
     /// Builder ident
     pub ident: Ident,
@@ -43,21 +46,73 @@ pub struct Builder {
     pub vis: Visibility,
     /// Builder mode
     pub mode: Mode,
+    /// Builder setters style
+    pub style: Style,
     /// Builder fields
     pub properties: Properties,
     /// Is Tuple struct `(a, b, c)` or Named one `{ a:A, b:B, c:C }`
     pub is_tuple: bool,
     /// Is Default implemented for struct
     pub default: Setting<()>,
+    /// Is Clone implemented for struct
+    pub clone: Setting<()>,
+    /// Is `build_cloned()` generated on builder
+    pub build_cloned: Setting<()>,
+    /// Are `with_<field>()` functions generated on struct
+    pub withers: Setting<()>,
+    /// Patch ident (when patch struct is generated)
+    pub patch: Setting<Ident>,
+    /// Extra derives for builder struct
+    pub derives: Vec<Path>,
+    /// Extra attributes for builder struct
+    pub attrs: Vec<Meta>,
+    /// Path to runtime crate (`::macon` by default)
+    pub krate: Path,
+    /// Are setters and `build()` generated as `const fn`
+    pub konst: Setting<()>,
+    /// Are setters only available in fields declaration order
+    pub ordered: Setting<()>,
+    /// Dynamic builder ident (when runtime-checked builder is generated along `Typestate` one)
+    pub dynamic: Setting<Ident>,
+    /// Header of `build()` panic message (only for `Panic` mode)
+    pub panic_message: Setting<String>,
 }
 
-#[derive(Debug,PartialEq)]
+#[derive(Clone,Debug,Default,PartialEq)]
 pub enum Mode {
+    #[default]
     Typestate,
     Result,
     Panic,
 }
 
+#[derive(Clone,Copy,Debug,Default,PartialEq)]
+pub enum Style {
+    /// Setters consume and return builder (`self -> Self`)
+    #[default]
+    Owned,
+    /// Setters borrow builder mutably (`&mut self -> &mut Self`)
+    MutRef,
+}
+
+#[derive(Clone,Copy,Debug,PartialEq,)]
+#[allow(clippy::enum_variant_names)]
+pub enum GroupKind {
+    ExactlyOne,
+    AtLeastOne,
+    AtMostOne,
+}
+
+#[derive(Clone,Debug)]
+pub struct Group {
+    /// Group ident
+    pub ident: Ident,
+    /// Constraint on group members count
+    pub kind: GroupKind,
+    /// Member field names (declaration order)
+    pub members: Vec<String>,
+}
+
 #[derive(Clone,Copy,Debug,PartialEq,)]
 pub enum Setter {
     Standard,
@@ -67,14 +122,7 @@ pub enum Setter {
     Optional,
 }
 
-#[derive(Debug,Default)]
-pub struct PropertySettings {
-    pub option: Setting<Type>,
-    pub default: Setting<()>,
-    pub into: Setting<()>,
-}
-
-#[derive(Debug,Default,)]
+#[derive(Clone,Debug,Default,)]
 pub struct Properties {
     /// Is Tuple struct `(a,b,c)` or Named one `{ a:A, b:B, c:C }`
     pub is_tuple: bool,
@@ -84,6 +132,10 @@ pub struct Properties {
     pub default: Setting<()>,
     /// Is Option supported for fields
     pub option: Setting<()>,
+    /// Can fields be set many times
+    pub overwrite: Setting<()>,
+    /// Field groups
+    pub groups: Vec<Group>,
     /// Struct fields
     items: Vec<Property>,
 }
@@ -105,10 +157,29 @@ impl Properties {
             .map(to_token)
             .collect()
     }
+
+    pub fn get_by_name(&self, name: &str) -> Option<&Property> {
+        self.iter().find(|p| p.name == name)
+    }
+
+    pub fn get_group(&self, property: &Property) -> Option<&Group> {
+        property
+            .group
+            .as_ref()
+            .and_then(|ident| self.groups.iter().find(|g| g.ident == *ident))
+    }
+
+    /// Check if `property` can't be set anymore once `target` is set
+    pub fn is_exclusive(&self, property: &Property, target: &Property) -> bool {
+        property.name != target.name &&
+        property.group.is_some() &&
+        property.group == target.group &&
+        self.get_group(property).map(|g| g.kind.is_exclusive()).unwrap_or(false)
+    }
 }
 
 
-#[derive(Debug)]
+#[derive(Clone,Debug)]
 pub struct Property {
     /// Field order (mostly for tuple)
     pub ordinal: usize,
@@ -120,6 +191,8 @@ pub struct Property {
     pub ty: Type,
     /// Is Tuple struct field `(a,b,c)` or Named one `{ a:A, b:B, c:C }`
     pub is_tuple: bool,
+    /// Is Tuple struct field named by attribute (setters use [`Self::ident`] instead of ordinal)
+    pub is_renamed: bool,
     /// Is Option and associated wrapped type
     pub option: Setting<Type>,
     /// Is Default supported for field
@@ -128,6 +201,24 @@ pub struct Property {
     pub into: Setting<()>,
     /// Is Default supported for struct
     pub struct_default: Setting<()>,
+    /// Group membership
+    pub group: Option<Ident>,
+    /// Group kind declared on field (resolved one is in [`Properties::groups`])
+    pub group_kind: Setting<GroupKind>,
+    /// Extra attributes for setters
+    pub setter_attrs: Vec<Meta>,
+    /// Is value redacted from builder `Debug` output
+    pub sensitive: Setting<()>,
+    /// Can field be set many times (setters stay available once set)
+    pub overwrite: Setting<()>,
+    /// Must (Option) field be explicitly set, either to a value or to `None`
+    pub explicit: Setting<()>,
+    /// Path to runtime crate (see [`Builder::krate`])
+    pub krate: Path,
+    /// Is builder generated as `const fn` (see [`Builder::konst`])
+    pub konst: Setting<()>,
+    /// Are setters only available in fields declaration order (see [`Builder::ordered`])
+    pub ordered: Setting<()>,
 }
 
 impl TryFrom<&Setting<String>> for Mode {
@@ -140,19 +231,120 @@ impl TryFrom<&Setting<String>> for Mode {
                     "Typestate" => Mode::Typestate,
                     "Result" => Mode::Result,
                     "Panic" => Mode::Panic,
-                    _ => return Err(Error::new(span.clone(), format!("Unsupported mode value {} for struct builder attribute", value))),
+                    _ => return Err(Error::new(*span, format!("Unsupported mode value {} for struct builder attribute", value))),
                 }
             }
-            Setting::Disabled { span } => return Err(Error::new(span.clone(), format!("Unsupported disabled mode for struct builder attribute"))),
+            Setting::Disabled { span } => return Err(Error::new(*span, "Unsupported disabled mode for struct builder attribute")),
         })
     }
 }
-impl Default for Mode {
-    fn default() -> Self {
-        Self::Typestate
+
+impl TryFrom<&Setting<String>> for Style {
+    type Error = Error;
+    fn try_from(value: &Setting<String>) -> Result<Self> {
+        Ok(match value {
+            Setting::Undefined => Style::default(),
+            Setting::Enabled { value, span } => {
+                match value.as_str() {
+                    "Owned" => Style::Owned,
+                    "MutRef" => Style::MutRef,
+                    _ => return Err(Error::new(*span, format!("Unsupported style value {} for struct builder attribute", value))),
+                }
+            }
+            Setting::Disabled { span } => return Err(Error::new(*span, "Unsupported disabled style for struct builder attribute")),
+        })
+    }
+}
+
+impl TryFrom<&Setting<Ident>> for GroupKind {
+    type Error = Error;
+    fn try_from(value: &Setting<Ident>) -> Result<Self> {
+        match value {
+            Setting::Enabled { value, span } => {
+                match value.to_string().as_str() {
+                    "exactly_one" => Ok(GroupKind::ExactlyOne),
+                    "at_least_one" => Ok(GroupKind::AtLeastOne),
+                    "at_most_one" => Ok(GroupKind::AtMostOne),
+                    _ => Err(Error::new(*span, format!("Unsupported group kind {} (expected exactly_one, at_least_one or at_most_one)", value))),
+                }
+            },
+            _ => Err(Error::new(Span::call_site(), "Missing group kind")),
+        }
+    }
+}
+
+impl GroupKind {
+    /// Setting a member prevents setting other ones
+    pub fn is_exclusive(&self) -> bool {
+        matches!(self, Self::ExactlyOne | Self::AtMostOne)
+    }
+
+    /// Building requires at least one member to be set
+    pub fn is_required(&self) -> bool {
+        matches!(self, Self::ExactlyOne | Self::AtLeastOne)
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::ExactlyOne => "exactly one",
+            Self::AtLeastOne => "at least one",
+            Self::AtMostOne => "at most one",
+        }
+    }
+
+    /// Check if members count satisfies constraint
+    pub fn accept(&self, count: usize) -> bool {
+        match self {
+            Self::ExactlyOne => count == 1,
+            Self::AtLeastOne => count >= 1,
+            Self::AtMostOne => count <= 1,
+        }
+    }
+}
+
+impl Group {
+    pub fn trait_ident(&self, builder: &Ident) -> Ident {
+        format_ident!("{}Group{}", builder, camel_case(&self.ident.to_string()))
+    }
+
+    /// Type-level counts of set members (see `::macon::__private::Count`) satisfying group constraint
+    pub fn typestate_counts(&self, krate: &Path) -> Vec<TokenStream> {
+        [(0, "Zero"), (1, "One"), (2, "Many")]
+            .into_iter()
+            .filter(|(count, _)| self.kind.accept(*count))
+            .map(|(_, name)| {
+                let ident = format_ident!("{}", name);
+                quote!(#krate::__private::#ident)
+            })
+            .collect()
+    }
+
+    pub fn message(&self, properties: &Properties) -> String {
+        let members = self
+            .members
+            .iter()
+            .filter_map(|name| properties.get_by_name(name))
+            .map(|p| p.label())
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("Group {} requires {} of {} to be set", self.ident, self.kind.description(), members)
     }
 }
 
+/// Convert `snake_case` name into `CamelCase` (to build type names)
+fn camel_case(name: &str) -> String {
+    name
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
 impl Default for Builder {
     fn default() -> Self {
         Self {
@@ -160,9 +352,21 @@ impl Default for Builder {
             target: format_ident!("Anonymous"),
             vis: Visibility::Inherited,
             mode: Default::default(),
+            style: Default::default(),
             properties: Default::default(),
             is_tuple: false,
             default: Default::default(),
+            clone: Default::default(),
+            build_cloned: Default::default(),
+            withers: Default::default(),
+            patch: Default::default(),
+            derives: Default::default(),
+            attrs: Default::default(),
+            krate: syn::parse_quote!(::macon),
+            konst: Default::default(),
+            ordered: Default::default(),
+            dynamic: Default::default(),
+            panic_message: Default::default(),
         }
     }
 }
@@ -177,22 +381,115 @@ impl Builder {
         let derives = Derives::from_input(&derive)?;
         this.with_attributes(builder_attr, derives)?;
         this.with_data(derive.data)?;
+        this.with_groups()?;
         Ok(this)
     }
 
+    /// Check if builder struct derives given trait (from `derive(...)` setting)
+    pub fn derives_trait(&self, name: &str) -> bool {
+        self.derives
+            .iter()
+            .any(|path| path.segments.last().map(|s| s.ident == name).unwrap_or(false))
+    }
+
+    /// Builder struct attributes: derives (`Default` is always derived, except for `Typestate` mode which implements it for initial state only) and extra attributes
+    pub fn struct_attrs(&self) -> TokenStream {
+        let derive_default = if self.mode == Mode::Typestate {
+            quote!()
+        } else {
+            quote!(Default,)
+        };
+        let derives = self.derives
+            .iter()
+            .filter(|path| ! path.is_ident("Default"));
+        let attrs = &self.attrs;
+        quote! {
+            #[derive(#derive_default #(#derives,)*)]
+            #(#[#attrs])*
+        }
+    }
+
     pub fn with_attributes(&mut self, builder: StructBuilder, derives: Derives) -> Result<()> {
         self.mode = builder.mode().try_into()?;
-        self.set_default(builder.default().clone());
+        self.style = builder.style().try_into()?;
+        if self.style == Style::MutRef && self.mode == Mode::Typestate {
+            let span = builder.style().span().copied().unwrap_or_else(Span::call_site);
+            return Err(Error::new(span, "Unsupported style MutRef for Typestate mode"));
+        }
+        self.set_default(*builder.default());
+        self.withers = *builder.withers();
+        self.patch = builder.patch().map(|_| format_ident!("{}Patch", self.target));
+        self.derives = builder.derives().clone();
+        self.attrs = builder.attrs().clone();
+        if let Some(krate) = builder.krate().value() {
+            self.krate = krate.clone();
+        }
+
+        self.properties.option  = *builder.fields().option();
+        self.properties.default = *builder.fields().default();
+        self.properties.into    = *builder.fields().into_();
+        self.properties.overwrite = *builder.overwrite();
+
+        self.konst = *builder.konst();
+        if self.konst.is_enabled() {
+            let span = self.konst.span().copied().unwrap_or_else(Span::call_site);
+            if self.mode != Mode::Typestate {
+                return Err(Error::new(span, format!("Unsupported const for {:?} mode", self.mode)));
+            }
+            if self.default.is_enabled() {
+                return Err(Error::new(span, "Unsupported const with Default struct"));
+            }
+            if self.properties.default.is_enabled() {
+                return Err(Error::new(span, "Unsupported const with Default fields"));
+            }
+            if self.properties.into.is_enabled() {
+                return Err(Error::new(span, "Unsupported const with Into fields"));
+            }
+            if self.properties.overwrite.is_enabled() {
+                return Err(Error::new(span, "Unsupported const with overwrite"));
+            }
+            self.set_default(Setting::disable(span));
+            self.properties.default = Setting::disable(span);
+            self.properties.into = Setting::disable(span);
+        }
+
+        self.dynamic = builder.dynamic().map(|_| format_ident!("{}DynamicBuilder", self.target));
+        if let Some(span) = self.dynamic.span() {
+            if self.mode != Mode::Typestate {
+                return Err(Error::new(*span, format!("Unsupported dynamic for {:?} mode", self.mode)));
+            }
+        }
+
+        self.panic_message = builder.panic_message().clone();
+        if let Some(span) = self.panic_message.span() {
+            if self.mode != Mode::Panic {
+                return Err(Error::new(*span, format!("Unsupported panic_message for {:?} mode", self.mode)));
+            }
+        }
 
-        self.properties.option  = builder.fields().option().clone();
-        self.properties.default = builder.fields().default().clone();
-        self.properties.into    = builder.fields().into_().clone();
+        self.ordered = *builder.ordered();
+        if self.ordered.is_enabled() {
+            let span = self.ordered.span().copied().unwrap_or_else(Span::call_site);
+            if self.mode != Mode::Typestate {
+                return Err(Error::new(span, format!("Unsupported ordered for {:?} mode", self.mode)));
+            }
+            if self.properties.overwrite.is_enabled() {
+                return Err(Error::new(span, "Unsupported ordered with overwrite"));
+            }
+        }
 
         if ! self.default.is_defined() {
             if let Some(span) = derives.get_type("Default") {
-                self.set_default(Setting::enable((), span.clone()));
+                self.set_default(Setting::enable((), *span));
             }
         }
+        if let Some(span) = derives.get_type("Clone") {
+            self.clone = Setting::enable((), *span);
+        }
+        self.build_cloned = *builder.clone();
+        if ! self.build_cloned.is_defined() {
+            self.build_cloned = self.clone;
+        }
         Ok(())
     }
 
@@ -215,6 +512,9 @@ impl Builder {
                         self.set_is_tuple(true);
                         for (ordinal, field)  in fields_unamed.unnamed.into_iter().enumerate() {
                             let value = Property::from_field(self, true, ordinal, field)?;
+                            if self.properties.get_by_name(&value.name).is_some() {
+                                return Err(Error::new(value.ident.span(), format!("Field {}: name is already used by another field", value.name)));
+                            }
                             self.properties.push(value);
                         }
                         Ok(())
@@ -230,11 +530,88 @@ impl Builder {
         }
     }
 
+    pub fn with_groups(&mut self) -> Result<()> {
+        let mut groups: Vec<(Group, Setting<GroupKind>)> = vec![];
+        for property in self.properties.iter() {
+            let Some(ref ident) = property.group else {
+                continue;
+            };
+            if ! property.option.is_enabled() {
+                return Err(Error::new(ident.span(), format!("Field {}: group member must be an Option field", property.name)));
+            }
+            // Default instance may already have members set, it can't be checked by typestates
+            if self.mode == Mode::Typestate && self.default.is_enabled() {
+                return Err(Error::new(ident.span(), format!("Field {}: group is not supported with struct Default in Typestate mode", property.name)));
+            }
+            let index = match groups.iter().position(|(group, _)| group.ident == *ident) {
+                Some(index) => index,
+                None => {
+                    groups.push((
+                        Group {
+                            ident: ident.clone(),
+                            kind: GroupKind::ExactlyOne,
+                            members: vec![],
+                        },
+                        Setting::undefined(),
+                    ));
+                    groups.len() - 1
+                },
+            };
+            let (group, kind) = &mut groups[index];
+            group.members.push(property.name.clone());
+            if let Some(property_kind) = property.group_kind.value() {
+                match kind.value() {
+                    Some(group_kind) if group_kind != property_kind => {
+                        return Err(Error::new(
+                            *property.group_kind.span().unwrap(),
+                            format!("Field {}: group {} has been already declared as {}", property.name, ident, group_kind.description()),
+                        ));
+                    },
+                    _ => *kind = property.group_kind,
+                }
+            }
+        }
+        for (mut group, kind) in groups {
+            group.kind = match kind.value() {
+                Some(kind) => *kind,
+                None => return Err(Error::new(group.ident.span(), format!("Missing kind for group {} (expected exactly_one, at_least_one or at_most_one)", group.ident))),
+            };
+            self.properties.groups.push(group);
+        }
+        Ok(())
+    }
+
     pub fn set_is_tuple(&mut self, is_tuple: bool) {
         self.is_tuple = is_tuple;
         self.properties.is_tuple = is_tuple;
     }
 
+    /// Runtime-checked (`Result` mode) builder generated along `Typestate` one (only when `dynamic` is enabled)
+    pub fn dynamic_builder(&self) -> Option<Builder> {
+        let ident = self.dynamic.value()?.clone();
+        let mut dynamic = self.clone();
+        dynamic.ident = ident;
+        dynamic.mode = Mode::Result;
+        dynamic.konst = Setting::undefined();
+        dynamic.ordered = Setting::undefined();
+        dynamic.dynamic = Setting::undefined();
+        dynamic.withers = Setting::undefined();
+        dynamic.patch = Setting::undefined();
+        for property in dynamic.properties.items.iter_mut() {
+            property.konst = Setting::undefined();
+            property.ordered = Setting::undefined();
+        }
+        Some(dynamic)
+    }
+
+    /// Header of `build()` panic message (target name by default)
+    pub fn panic_header(&self) -> String {
+        self.panic_message
+            .value()
+            .cloned()
+            .unwrap_or_else(|| format!("Unable to build {}:", self.target))
+    }
+
     pub fn set_default(&mut self, default: Setting<()>) {
         self.default = default;
     }
@@ -247,6 +624,12 @@ impl Property {
         let span = field.ty.span();
         let builder_attribute = FieldBuilder::from_field(&field)
             .map_err_context(format!("Field {}", name))?;
+        let is_renamed = builder_attribute.name().is_enabled();
+        let (ident, name) = match builder_attribute.name().value() {
+            Some(_) if ! is_tuple => return Err(Error::new(span, format!("Field {}: name is only supported for tuple field", name))),
+            Some(rename) => (rename.clone(), rename.to_string()),
+            None => (ident, name),
+        };
         let option = if builder_attribute.option().is_undefined() {
             if builder.properties.option.is_disabled() {
                 Setting::disable(span)
@@ -258,8 +641,23 @@ impl Property {
         } else {
             builder_attribute.option().clone()
         };
+        let explicit = *builder_attribute.explicit();
+        if explicit.is_enabled() {
+            if ! option.is_enabled() {
+                return Err(Error::new(span, format!("Field {}: explicit is only supported for Option field", name)));
+            }
+            if builder_attribute.default().is_enabled() {
+                return Err(Error::new(span, format!("Field {}: explicit is not supported with Default", name)));
+            }
+            if builder.default.is_enabled() {
+                return Err(Error::new(span, format!("Field {}: explicit is not supported with struct Default", name)));
+            }
+            if builder_attribute.group().is_defined() {
+                return Err(Error::new(span, format!("Field {}: explicit is not supported for group member", name)));
+            }
+        }
         let default = if builder_attribute.default().is_undefined() {
-            if builder.properties.default.is_disabled() {
+            if builder.properties.default.is_disabled() || explicit.is_enabled() {
                 Setting::disable(span)
             } else {
                 let default_types = crate::config::get().default_types();
@@ -270,12 +668,49 @@ impl Property {
                 }
             }
         } else {
-            builder_attribute.default().clone()
+            *builder_attribute.default()
         };
         let into = if builder_attribute.into_().is_undefined() {
-            builder.properties.into.clone()
+            builder.properties.into
+        } else {
+            *builder_attribute.into_()
+        };
+        let overwrite = if builder_attribute.overwrite().is_undefined() {
+            builder.properties.overwrite
         } else {
-            builder_attribute.into_().clone()
+            *builder_attribute.overwrite()
+        };
+        if builder.konst.is_enabled() {
+            if overwrite.is_enabled() {
+                return Err(Error::new(span, format!("Field {}: overwrite is not supported by const builder", name)));
+            }
+            if default.is_enabled() {
+                return Err(Error::new(span, format!("Field {}: Default is not supported by const builder", name)));
+            }
+            if into.is_enabled() {
+                return Err(Error::new(span, format!("Field {}: Into is not supported by const builder", name)));
+            }
+        }
+        if builder.ordered.is_enabled() {
+            if overwrite.is_enabled() {
+                return Err(Error::new(span, format!("Field {}: overwrite is not supported by ordered builder", name)));
+            }
+            if builder_attribute.group().is_defined() {
+                return Err(Error::new(span, format!("Field {}: group is not supported by ordered builder", name)));
+            }
+        }
+        let (group, group_kind) = match builder_attribute.group().value() {
+            Some(group) => {
+                let kind = match group.kind().span() {
+                    Some(span) => Setting::enable(
+                        GroupKind::try_from(group.kind()).map_err_context(format!("Field {}", name))?,
+                        *span,
+                    ),
+                    None => Setting::undefined(),
+                };
+                (Some(group.name().clone()), kind)
+            },
+            None => (None, Setting::undefined()),
         };
         Ok(Self {
             ordinal,
@@ -283,10 +718,20 @@ impl Property {
             ident,
             ty: field.ty,
             is_tuple,
+            is_renamed,
             option,
             default,
             into,
-            struct_default: builder.default.clone(),
+            struct_default: builder.default,
+            group,
+            group_kind,
+            setter_attrs: builder_attribute.setter_attrs().clone(),
+            sensitive: *builder_attribute.sensitive(),
+            overwrite,
+            explicit,
+            krate: builder.krate.clone(),
+            konst: builder.konst,
+            ordered: builder.ordered,
         })
     }
 
@@ -335,8 +780,22 @@ impl Property {
         self.option.value().unwrap_or(&self.ty)
     }
 
-    pub fn setter(&self) -> Cow<Ident> {
-        if self.is_tuple {
+    /// Is field only refered by its ordinal (tuple field without name)
+    pub fn is_positional(&self) -> bool {
+        self.is_tuple && ! self.is_renamed
+    }
+
+    /// Field name in messages (ordinal for positional field)
+    pub fn label(&self) -> String {
+        if self.is_positional() {
+            self.ordinal.to_string()
+        } else {
+            self.name.clone()
+        }
+    }
+
+    pub fn setter(&self) -> Cow<'_, Ident> {
+        if self.is_positional() {
             Cow::Owned(format_ident!("set{}", self.ordinal))
         } else {
             Cow::Borrowed(&self.ident)
@@ -358,6 +817,75 @@ impl Property {
         format_ident!("{}_default", self.setter())
     }
 
+    pub fn wither(&self) -> Ident {
+        if self.is_positional() {
+            format_ident!("with{}", self.ordinal)
+        } else {
+            format_ident!("with_{}", self.ident)
+        }
+    }
+
+    pub fn wither_none(&self) -> Ident {
+        format_ident!("{}_none", self.wither())
+    }
+
+    pub fn wither_optional(&self) -> Ident {
+        format_ident!("{}_optional", self.wither())
+    }
+
+    /// Builder field state to be formatted by `Debug` implementation (see `::macon::Debugging`).
+    ///
+    /// Read from field storage (same in all modes), so typestate isn't involved.
+    pub fn debug_state(&self) -> TokenStream {
+        let krate = &self.krate;
+        let id = self.id();
+        let mut state = if self.is_required() || self.default.is_enabled() || self.struct_default.is_enabled() {
+            quote!(self.#id.debugging())
+        } else {
+            quote!(#krate::Debugging::Set(&self.#id))
+        };
+        if self.default.is_enabled() && self.struct_default.is_enabled() {
+            state = quote!(#state.flatten());
+        }
+        if self.sensitive.is_enabled() {
+            state = quote!(#state.redact());
+        }
+        state
+    }
+
+    /// Add builder field state to `debug` formatter
+    pub fn debug_field(&self) -> TokenStream {
+        let state = self.debug_state();
+        let name = self.id().to_string();
+        if self.is_tuple {
+            quote!(debug.field(&#state);)
+        } else {
+            quote!(debug.field(#name, &#state);)
+        }
+    }
+
+    /// Extra attributes for setters
+    pub fn setter_attrs(&self) -> TokenStream {
+        let attrs = &self.setter_attrs;
+        quote!(#(#[#attrs])*)
+    }
+
+    pub fn getter(&self) -> Ident {
+        if self.is_positional() {
+            format_ident!("get{}", self.ordinal)
+        } else {
+            format_ident!("get_{}", self.ident)
+        }
+    }
+
+    pub fn checker(&self) -> Ident {
+        if self.is_positional() {
+            format_ident!("is_set{}", self.ordinal)
+        } else {
+            format_ident!("is_{}_set", self.ident)
+        }
+    }
+
     pub fn prefix(&self) -> TokenStream {
         if self.is_tuple {
             quote!()
@@ -368,18 +896,36 @@ impl Property {
     }
 
     pub fn is_required(&self) -> bool {
-        ! self.option.is_enabled() &&
-        ! self.default.is_enabled() &&
-        ! self.struct_default.is_enabled() &&
-        true
+        self.explicit.is_enabled() || (
+            ! self.option.is_enabled() &&
+            ! self.default.is_enabled() &&
+            ! self.struct_default.is_enabled()
+        )
+    }
+
+    /// Is field state tracked by a builder typevar in `Typestate` mode. Only fields required to build and group members are
+    /// tracked, unless every field is (tuple, `ordered` or `const` builder). Other fields setters stay available once set.
+    pub fn is_tracked(&self) -> bool {
+        self.is_required() ||
+        self.group.is_some() ||
+        self.is_tuple ||
+        self.konst.is_enabled() ||
+        self.ordered.is_enabled()
     }
 
+    /// Builder typevar (`Some(true)`), set typestate (`Some(false)`) or unset typestate (`None`), only for tracked field
     pub fn typestate(&self, is_var: Option<bool>) -> TokenStream {
+        if ! self.is_tracked() {
+            return quote!();
+        }
         match is_var {
-            None => quote!((),),
+            None => {
+                let krate = &self.krate;
+                quote!(#krate::Unset,)
+            },
             Some(false) => {
-                let ty = &self.ty;
-                quote!(#ty,)
+                let krate = &self.krate;
+                quote!(#krate::Set,)
             },
             Some(true) => {
                 let typevar = self.typevar();
@@ -390,25 +936,33 @@ impl Property {
 
     pub fn typestate_struct_field(&self) -> TokenStream {
         let prefix = self.prefix();
-        let ty = if ! self.is_required() {
-            let mut ty = self.ty.to_token_stream();
-            if self.default.is_enabled() {
-                ty = quote!(::macon::Defaulting<#ty>);
-            }
-            if self.struct_default.is_enabled() {
-                ty = quote!(::macon::Keeping<#ty>);
-            }
-            ty
-        } else {
-            self.typevar().to_token_stream()
-        };
+        let ty = self.typestate_field_type();
         quote!(#prefix #ty,)
     }
 
+    /// Builder field storage type (same as `Panic`/`Result` modes, state is tracked by typevars)
+    pub fn typestate_field_type(&self) -> TokenStream {
+        self.result_field_type()
+    }
+
+    /// Field name in `CamelCase` (to build type names)
+    pub fn typestate_camel_name(&self) -> String {
+        camel_case(&self.name)
+    }
+
+    /// Marker trait implemented by unset typestate (bounds setters)
+    pub fn typestate_unset_trait(&self, builder: &Ident) -> Ident {
+        format_ident!("{}{}Unset", builder, self.typestate_camel_name())
+    }
+
+    /// Trait implemented by builder typestates where field is set (bounds `build()` for required fields)
+    pub fn typestate_has_trait(&self, builder: &Ident) -> Ident {
+        format_ident!("{}Has{}", builder, self.typestate_camel_name())
+    }
+
     pub fn typestate_optional_marker(&self) -> TokenStream {
         if ! self.is_required() {
-            let typevar = self.typevar();
-            quote!(#typevar,)
+            self.typestate(Some(true))
         } else {
             quote!()
         }
@@ -450,89 +1004,124 @@ impl Property {
         }
     }
 
-    pub fn typestate_assign(&self, target: &Property, setter: Setter) -> TokenStream {
+    pub fn patch_field(&self) -> TokenStream {
         let prefix = self.prefix();
-        let ident = &self.ident;
-        let value = if self.name == target.name {
-            match setter {
-                Setter::Standard => {
-                    let mut value = if ! self.into.is_disabled() {
-                        quote!(#ident.into())
-                    } else {
-                        quote!(#ident)
-                    };
-                    if self.option.is_enabled() {
-                        value = quote!(::core::option::Option::Some(#value));
-                    }
-                    if self.default.is_enabled() {
-                        value = quote!(::macon::Defaulting::Set(#value));
-                    }
-                    if self.struct_default.is_enabled() {
-                        value = quote!(::macon::Keeping::Set(#value));
-                    }
-                    value
-                },
-                Setter::None => {
-                    let mut value = quote!(::core::option::Option::None);
-                    if self.default.is_enabled() {
-                        value = quote!(::macon::Defaulting::Set(#value));
-                    }
-                    if self.struct_default.is_enabled() {
-                        value = quote!(::macon::Keeping::Set(#value));
-                    }
-                    value
-                },
-                Setter::Keep => quote!(::macon::Keeping::Keep),
-                Setter::Default => {
-                    let mut value = quote!(::macon::Defaulting::Default);
-                    if self.struct_default.is_enabled() {
-                        value = quote!(::macon::Keeping::Set(#value));
-                    }
-                    value
-                },
-                Setter::Optional => {
-                    let mut value = if ! self.into.is_disabled() {
-                        quote!(#ident.map(::core::convert::Into::into))
-                    } else {
-                        quote!(#ident)
-                    };
-                    if self.default.is_enabled() {
-                        value = quote!(::macon::Defaulting::Set(#value));
-                    }
-                    if self.struct_default.is_enabled() {
-                        value = quote!(::macon::Keeping::Set(#value));
-                    }
-                    value
-                }
+        let ty = &self.ty;
+        quote!(#prefix ::core::option::Option<#ty>,)
+    }
+
+    /// Merge field patches, `other` taking precedence when set (including explicit `None` stored as `Some(None)`)
+    pub fn patch_merge(&self) -> TokenStream {
+        let prefix = self.prefix();
+        let id = self.id();
+        quote!(#prefix other.#id.or(self.#id),)
+    }
+
+    pub fn patch_apply(&self) -> TokenStream {
+        let id = self.id();
+        quote! {
+            if let ::core::option::Option::Some(value) = self.#id {
+                target.#id = value;
             }
+        }
+    }
+
+    /// Move value out of builder field storage (bitwise read in `const fn`, builder must be forgotten afterwards)
+    pub fn typestate_read(&self) -> TokenStream {
+        let id = self.id();
+        if self.konst.is_enabled() {
+            quote!(unsafe { ::core::ptr::read(&self.#id) })
         } else {
-            let id = self.id();
             quote!(self.#id)
+        }
+    }
+
+    /// Initial builder field storage (all fields unset)
+    pub fn typestate_init(&self) -> TokenStream {
+        let krate = &self.krate;
+        let prefix = self.prefix();
+        let value = if self.is_required() {
+            quote!(#krate::Building::Undefined)
+        } else if self.struct_default.is_enabled() {
+            quote!(#krate::Keeping::Keep)
+        } else if self.default.is_enabled() {
+            quote!(#krate::Defaulting::Default)
+        } else {
+            quote!(::core::option::Option::None)
         };
         quote!(#prefix #value,)
     }
 
-    pub fn typestate_value(&self) -> TokenStream {
-        let id = self.id();
-        let mut value = quote!(self.#id);
-        if self.struct_default.is_enabled() {
-            value = quote!(#value.unwrap());
-        }
+    /// Wrap (already converted) `value` into builder field storage
+    pub fn typestate_set(&self, value: TokenStream) -> TokenStream {
+        let krate = &self.krate;
+        let mut value = value;
         if self.default.is_enabled() {
-            value = quote!(#value.unwrap());
+            value = quote!(#krate::Defaulting::Set(#value));
+        }
+        if self.struct_default.is_enabled() {
+            value = quote!(#krate::Keeping::Set(#value));
         }
         value
     }
 
-    pub fn typestate_build(&self) -> TokenStream {
+    pub fn typestate_from_target(&self) -> TokenStream {
+        let prefix = self.prefix();
+        let id = self.id();
+        let value = self.result_set(quote!(target.#id));
+        quote!(#prefix #value,)
+    }
+
+    /// Move field storage into builder with another typestate
+    pub fn typestate_transition(&self) -> TokenStream {
+        let prefix = self.prefix();
+        let value = self.typestate_read();
+        quote!(#prefix #value,)
+    }
+
+    /// Assign field storage from setter (previous value is known to be unset, so it's overwritten without drop in `const fn`)
+    pub fn typestate_assign(&self, setter: Setter) -> TokenStream {
+        if self.konst.is_enabled() {
+            let id = self.id();
+            let value = self.storage_value(setter);
+            quote! {
+                unsafe { ::core::ptr::write(&mut self.#id, #value) };
+            }
+        } else {
+            self.result_assign(setter)
+        }
+    }
+
+    /// Extract value from builder field storage (`cloned` when builder is borrowed)
+    pub fn typestate_value(&self, cloned: bool) -> TokenStream {
+        if cloned || ! self.konst.is_enabled() {
+            return self.result_value(cloned);
+        }
+        let krate = &self.krate;
+        let id = self.id();
+        let value = self.typestate_read();
+        if self.is_required() {
+            let message = format!("Field {} is missing", self.label());
+            quote! {
+                match &self.#id {
+                    #krate::Building::Set(value) => unsafe { ::core::ptr::read(value) },
+                    #krate::Building::Undefined => ::core::panic!(#message),
+                }
+            }
+        } else {
+            value
+        }
+    }
+
+    pub fn typestate_build(&self, cloned: bool) -> TokenStream {
         let prefix = self.prefix();
-        let value = self.typestate_value();
+        let value = self.typestate_value(cloned);
         quote!(#prefix #value,)
     }
 
-    pub fn typestate_override(&self) -> TokenStream {
+    pub fn typestate_override(&self, cloned: bool) -> TokenStream {
         let id = self.id();
-        let value = self.typestate_value();
+        let value = self.typestate_value(cloned);
         quote! {
             if self.#id.is_set() {
                 built.#id = #value;
@@ -542,24 +1131,54 @@ impl Property {
 
     pub fn result_field(&self) -> TokenStream {
         let prefix = self.prefix();
+        let ty = self.result_field_type();
+        quote!(#prefix #ty,)
+    }
+
+    /// Builder field storage type
+    pub fn result_field_type(&self) -> TokenStream {
+        let krate = &self.krate;
         let mut ty = self.ty.to_token_stream();
         if ! self.is_required() {
             if self.default.is_enabled() {
-                ty = quote!(::macon::Defaulting<#ty>);
+                ty = quote!(#krate::Defaulting<#ty>);
             }
             if self.struct_default.is_enabled() {
-                ty = quote!(::macon::Keeping<#ty>);
+                ty = quote!(#krate::Keeping<#ty>);
             }
         } else {
-            ty = quote!(::macon::Building<#ty>);
+            ty = quote!(#krate::Building<#ty>);
         }
-        quote!(#prefix #ty,)
+        ty
     }
 
-    pub fn result_assign(&self, setter: Setter) -> TokenStream {
-        let ident = &self.ident;
+    pub fn clone_field(&self) -> TokenStream {
+        let prefix = self.prefix();
         let id = self.id();
-        let mut value = match setter {
+        quote!(#prefix ::core::clone::Clone::clone(&self.#id),)
+    }
+
+    /// Wrap (already converted) `value` into builder field storage
+    pub fn result_set(&self, value: TokenStream) -> TokenStream {
+        let krate = &self.krate;
+        if self.is_required() {
+            quote!(#krate::Building::Set(#value))
+        } else {
+            self.typestate_set(value)
+        }
+    }
+
+    pub fn result_from_target(&self) -> TokenStream {
+        let prefix = self.prefix();
+        let id = self.id();
+        let value = self.result_set(quote!(target.#id));
+        quote!(#prefix #value,)
+    }
+
+    /// Field value from setter argument (only for [`Setter::Standard`], [`Setter::None`] and [`Setter::Optional`])
+    pub fn setter_value(&self, setter: Setter) -> TokenStream {
+        let ident = &self.ident;
+        match setter {
             Setter::Standard => {
                 let mut value = quote!(#ident);
                 if ! self.into.is_disabled() {
@@ -571,8 +1190,6 @@ impl Property {
                 value
             },
             Setter::None => quote!(::core::option::Option::None),
-            Setter::Keep => quote!(::macon::Keeping::Keep),
-            Setter::Default => quote!(::macon::Defaulting::Default),
             Setter::Optional => {
                 let mut value = quote!(#ident);
                 if ! self.into.is_disabled() {
@@ -580,27 +1197,46 @@ impl Property {
                 }
                 value
             },
+            _ => panic!("Unsupported setter {:?} for value", setter),
+        }
+    }
+
+    /// Builder field storage value from setter
+    pub fn storage_value(&self, setter: Setter) -> TokenStream {
+        let krate = &self.krate;
+        let mut value = match setter {
+            Setter::Keep => quote!(#krate::Keeping::Keep),
+            Setter::Default => quote!(#krate::Defaulting::Default),
+            _ => self.setter_value(setter),
         };
         if ! self.is_required() {
             if setter != Setter::Keep {
-                if setter != Setter::Default {
-                    if self.default.is_enabled() {
-                        value = quote!(::macon::Defaulting::Set(#value));
-                    }
+                if setter != Setter::Default && self.default.is_enabled() {
+                    value = quote!(#krate::Defaulting::Set(#value));
                 }
                 if self.struct_default.is_enabled() {
-                    value = quote!(::macon::Keeping::Set(#value));
+                    value = quote!(#krate::Keeping::Set(#value));
                 }
             }
         } else {
-            value = quote!(::macon::Building::Set(#value));
+            value = quote!(#krate::Building::Set(#value));
         }
+        value
+    }
+
+    pub fn result_assign(&self, setter: Setter) -> TokenStream {
+        let id = self.id();
+        let value = self.storage_value(setter);
         quote!(self.#id = #value;)
     }
 
-    pub fn result_value(&self) -> TokenStream {
+    /// Extract value from builder field storage (`cloned` when builder is borrowed)
+    pub fn result_value(&self, cloned: bool) -> TokenStream {
         let id = self.id();
         let mut value = quote!(self.#id);
+        if cloned {
+            value = quote!(::core::clone::Clone::clone(&#value));
+        }
         if ! self.is_required() {
             if self.struct_default.is_enabled() {
                 value = quote!(#value.unwrap());
@@ -614,15 +1250,15 @@ impl Property {
         value
     }
 
-    pub fn result_build(&self) -> TokenStream {
+    pub fn result_build(&self, cloned: bool) -> TokenStream {
         let prefix = self.prefix();
-        let value = self.result_value();
+        let value = self.result_value(cloned);
         quote!(#prefix #value,)
     }
 
-    pub fn result_override(&self) -> TokenStream {
+    pub fn result_override(&self, cloned: bool) -> TokenStream {
         let id = self.id();
-        let value = self.result_value();
+        let value = self.result_value(cloned);
         quote! {
             if self.#id.is_set() {
                 built.#id = #value;
@@ -630,6 +1266,36 @@ impl Property {
         }
     }
 
+    /// Borrow value provided to builder (if any)
+    pub fn result_get(&self) -> TokenStream {
+        let krate = &self.krate;
+        let id = self.id();
+        if self.is_required() || self.struct_default.is_enabled() {
+            let mut value = quote!(self.#id.get());
+            if ! self.is_required() && self.default.is_enabled() {
+                value = quote!(#value.and_then(#krate::Defaulting::get));
+            }
+            value
+        } else if self.default.is_enabled() {
+            quote!(self.#id.get())
+        } else {
+            quote!(::core::option::Option::Some(&self.#id).filter(|value| value.is_some()))
+        }
+    }
+
+    /// Check if (optional) value is present, either from builder or from built instance (`Default` struct)
+    pub fn result_is_present(&self, from_default: bool) -> TokenStream {
+        let krate = &self.krate;
+        let id = self.id();
+        if from_default {
+            quote!(built.#id.is_some())
+        } else if self.default.is_enabled() {
+            quote!(::core::matches!(self.#id, #krate::Defaulting::Set(::core::option::Option::Some(_))))
+        } else {
+            quote!(self.#id.is_some())
+        }
+    }
+
 }
 
 impl Properties {
@@ -642,19 +1308,23 @@ impl Properties {
     }
 
     pub fn group(&self, stream: TokenStream) -> TokenStream {
-        Group::new(self.delimiter(), stream).to_token_stream()
+        TokenGroup::new(self.delimiter(), stream).to_token_stream()
     }
 
     pub fn typestate_default(&self) -> TokenStream {
-        self.to_token(|f| {
+        self.to_token(|f| if f.is_tracked() {
+            let krate = &f.krate;
             let typestate = f.typevar();
-            quote!(#typestate=(),)
+            quote!(#typestate=#krate::Unset,)
+        } else {
+            quote!()
         })
     }
 
     pub fn typestate_struct_fields(&self) -> TokenStream {
         let data_fields = self.to_token(|p| p.typestate_struct_field());
-        let marker_field = self.typestate_marker_field(self.typestate_optional_marker());
+        let typevars = self.to_token(|p| p.typestate(Some(true)));
+        let marker_field = self.typestate_marker_field(typevars);
         self.group(quote! {
             #data_fields
             #marker_field
@@ -674,7 +1344,11 @@ impl Properties {
     }
 
     pub fn typestate_state(&self, target: &Property, is_ordered: bool, is_set: bool) -> TokenStream {
-        self.to_token(|p| p.typestate_state(target, is_ordered, is_set))
+        self.to_token(|p| if ! is_ordered && self.is_exclusive(p, target) {
+            p.typestate(None)
+        } else {
+            p.typestate_state(target, is_ordered, is_set)
+        })
     }
 
     pub fn typestate_state_final(&self) -> TokenStream {
@@ -682,11 +1356,110 @@ impl Properties {
     }
 
     pub fn typestate_setter_impl(&self, target: &Property) -> TokenStream {
-        self.to_token(|p| p.typestate_setter_impl(target))
+        self.to_token(|p| if self.is_exclusive(p, target) {
+            quote!()
+        } else {
+            p.typestate_setter_impl(target)
+        })
+    }
+
+    /// Bounds on member typestates of required groups: set members are counted at type-level through `::macon::FieldState`
+    /// trait (see [`Group::typestate_counts()`]), so bounds grow linearly with group size
+    pub fn typestate_group_bounds(&self, builder: &Ident) -> TokenStream {
+        self.groups
+            .iter()
+            .filter(|g| g.kind.is_required())
+            .map(|g| {
+                let trait_ident = g.trait_ident(builder);
+                let members: Vec<&Property> = g.members
+                    .iter()
+                    .filter_map(|name| self.get_by_name(name))
+                    .collect();
+                let krate = &members[0].krate;
+                let states = members
+                    .iter()
+                    .map(|p| {
+                        let typevar = p.typevar();
+                        quote!(#typevar: #krate::FieldState,)
+                    });
+                let counts = members
+                    .iter()
+                    .rev()
+                    .fold(quote!(()), |tail, p| {
+                        let typevar = p.typevar();
+                        quote!((<#typevar as #krate::FieldState>::__Count, #tail))
+                    });
+                quote! {
+                    #(#states)*
+                    #counts: #krate::__private::Count,
+                    <#counts as #krate::__private::Count>::Sum: #trait_ident,
+                }
+            })
+            .collect()
     }
 
-    pub fn typestate_assign(&self, target: &Property, setter: Setter) -> TokenStream {
-        let data = self.to_token(|p| p.typestate_assign(target, setter));
+    /// Bounds on typestates of required fields to be set
+    pub fn typestate_required_bounds(&self, builder: &Ident) -> TokenStream {
+        self.to_token(|p| if p.is_required() {
+            let has_trait = p.typestate_has_trait(builder);
+            quote!(Self: #has_trait,)
+        } else {
+            quote!()
+        })
+    }
+
+    /// Move all fields storage into builder with another typestate
+    pub fn typestate_transition(&self) -> TokenStream {
+        let data = self.to_token(|p| p.typestate_transition());
+        let marker = if self.is_tuple {
+            quote!(::core::marker::PhantomData,)
+        } else {
+            quote!(__typestate_markers: ::core::marker::PhantomData,)
+        };
+        self.group(quote! {
+            #data
+            #marker
+        })
+    }
+
+    /// Move all fields storage into runtime-checked builder (same storage, with new cursor for tuples)
+    pub fn typestate_dynamic(&self) -> TokenStream {
+        let data = self.to_token(|p| p.typestate_transition());
+        let cursor = self.result_cursor_init();
+        self.group(quote! {
+            #data
+            #cursor
+        })
+    }
+
+    pub fn typestate_build(&self, cloned: bool) -> TokenStream {
+        self.group(self.to_token(|p| p.typestate_build(cloned)))
+    }
+
+    /// Initial builder (all fields unset)
+    pub fn typestate_init(&self) -> TokenStream {
+        let data = self.to_token(|p| p.typestate_init());
+        let marker = if self.is_tuple {
+            quote!(::core::marker::PhantomData,)
+        } else {
+            quote!(__typestate_markers: ::core::marker::PhantomData,)
+        };
+        self.group(quote! {
+            #data
+            #marker
+        })
+    }
+
+    /// `Clone` bounds for builder field types (higher-ranked to be checked only on use)
+    pub fn clone_bounds(&self) -> TokenStream {
+        self.to_token(|p| {
+            let ty = &p.ty;
+            quote!(for<'__macon> #ty: ::core::clone::Clone,)
+        })
+    }
+
+    pub fn typestate_clone(&self) -> TokenStream {
+        let data = self.to_token(|p| p.clone_field());
         let marker = if self.is_tuple {
             quote!(::core::default::Default::default(),)
         } else {
@@ -698,24 +1471,122 @@ impl Properties {
         })
     }
 
-    pub fn typestate_build(&self) -> TokenStream {
-        self.group(self.to_token(|p| p.typestate_build()))
+    /// All fields set state
+    pub fn typestate_state_complete(&self) -> TokenStream {
+        self.to_token(|p| p.typestate(Some(false)))
     }
 
-    pub fn typestate_override(&self) -> TokenStream {
-        self.to_token(|p| p.typestate_override())
+    pub fn typestate_from_target(&self) -> TokenStream {
+        let data = self.to_token(|p| p.typestate_from_target());
+        let marker = if self.is_tuple {
+            quote!(::core::default::Default::default(),)
+        } else {
+            quote!(__typestate_markers: ::core::default::Default::default(),)
+        };
+        self.group(quote! {
+            #data
+            #marker
+        })
     }
 
-    pub fn result_fields(&self) -> TokenStream {
-        self.group(self.to_token(|p| p.result_field()))
+    pub fn typestate_override(&self, cloned: bool) -> TokenStream {
+        self.to_token(|p| p.typestate_override(cloned))
     }
 
-    pub fn result_build(&self) -> TokenStream {
-        self.group(self.to_token(|p| p.result_build()))
+    /// Index of positional setters cursor, stored after fields (only for tuples)
+    pub fn result_cursor(&self) -> Option<Literal> {
+        if self.is_tuple {
+            Some(Literal::usize_unsuffixed(self.items.len()))
+        } else {
+            None
+        }
     }
 
-    pub fn result_override(&self) -> TokenStream {
-        self.to_token(|p| p.result_override())
+    /// Initial positional setters cursor (only for tuples)
+    pub fn result_cursor_init(&self) -> TokenStream {
+        if self.is_tuple {
+            quote!(::core::default::Default::default(),)
+        } else {
+            quote!()
+        }
+    }
+
+    pub fn result_fields(&self, krate: &Path) -> TokenStream {
+        let data = self.to_token(|p| p.result_field());
+        let cursor = if self.is_tuple {
+            quote!(#krate::__private::Cursor,)
+        } else {
+            quote!()
+        };
+        self.group(quote! {
+            #data
+            #cursor
+        })
+    }
+
+    pub fn result_build(&self, cloned: bool) -> TokenStream {
+        self.group(self.to_token(|p| p.result_build(cloned)))
+    }
+
+    pub fn result_from_target(&self) -> TokenStream {
+        let data = self.to_token(|p| p.result_from_target());
+        let cursor = self.result_cursor_init();
+        self.group(quote! {
+            #data
+            #cursor
+        })
+    }
+
+    pub fn result_clone(&self) -> TokenStream {
+        let data = self.to_token(|p| p.clone_field());
+        let cursor = match self.result_cursor() {
+            Some(cursor) => quote!(::core::clone::Clone::clone(&self.#cursor),),
+            None => quote!(),
+        };
+        self.group(quote! {
+            #data
+            #cursor
+        })
+    }
+
+    pub fn result_override(&self, cloned: bool) -> TokenStream {
+        self.to_token(|p| p.result_override(cloned))
+    }
+
+    pub fn patch_fields(&self) -> TokenStream {
+        self.group(self.to_token(|p| p.patch_field()))
+    }
+
+    pub fn patch_merge(&self) -> TokenStream {
+        self.group(self.to_token(|p| p.patch_merge()))
+    }
+
+    pub fn patch_apply(&self) -> TokenStream {
+        self.to_token(|p| p.patch_apply())
+    }
+
+    pub fn result_group_checks(&self, from_default: bool) -> TokenStream {
+        self.groups
+            .iter()
+            .map(|group| {
+                let present = group.members
+                    .iter()
+                    .filter_map(|name| self.get_by_name(name))
+                    .map(|p| p.result_is_present(from_default));
+                let message = group.message(self);
+                let accept = match group.kind {
+                    GroupKind::ExactlyOne => quote!(count == 1),
+                    GroupKind::AtLeastOne => quote!(count >= 1),
+                    GroupKind::AtMostOne => quote!(count <= 1),
+                };
+                quote! {
+                    let count: usize = #(usize::from(#present))+*;
+                    if !(#accept) {
+                        errors.push(#message.into());
+                    }
+                }
+            })
+            .collect()
     }
 }
 
@@ -970,4 +1841,401 @@ pub mod tests {
         property_opt = properties.next();
         assert!(property_opt.is_none(), "builder.properties.items[3]");
     }
+
+    #[test]
+    fn builder_derive_groups() {
+        let builder = newbuilder(parse_quote! {
+            struct Foobar {
+                #[builder(group(auth, exactly_one))]
+                password: Option<String>,
+                host: String,
+                #[builder(group(auth))]
+                token: Option<String>,
+                #[builder(group(proxy, at_most_one))]
+                proxy: Option<String>,
+            }
+        });
+
+        let actual: Vec<_> = builder.properties.groups
+            .iter()
+            .map(|g| (g.ident.to_string(), g.kind, g.members.clone()))
+            .collect();
+        assert_eq!(
+            actual,
+            vec![
+                (String::from("auth"), GroupKind::ExactlyOne, vec![String::from("password"), String::from("token")]),
+                (String::from("proxy"), GroupKind::AtMostOne, vec![String::from("proxy")]),
+            ],
+            "builder.properties.groups",
+        );
+    }
+
+    #[test]
+    fn builder_derive_groups_missing_kind() {
+        let actual = errbuilder(parse_quote! {
+            struct Foobar {
+                #[builder(group(auth))]
+                password: Option<String>,
+            }
+        });
+        assert_eq!(
+            actual,
+            Err(String::from("Missing kind for group auth (expected exactly_one, at_least_one or at_most_one)")),
+        );
+    }
+
+    #[test]
+    fn builder_derive_groups_conflicting_kind() {
+        let actual = errbuilder(parse_quote! {
+            struct Foobar {
+                #[builder(group(auth, exactly_one))]
+                password: Option<String>,
+                #[builder(group(auth, at_most_one))]
+                token: Option<String>,
+            }
+        });
+        assert_eq!(
+            actual,
+            Err(String::from("Field token: group auth has been already declared as exactly one")),
+        );
+    }
+
+    #[test]
+    fn builder_derive_groups_unknown_kind() {
+        let actual = errbuilder(parse_quote! {
+            struct Foobar {
+                #[builder(group(auth, all))]
+                password: Option<String>,
+            }
+        });
+        assert_eq!(
+            actual,
+            Err(String::from("Field password: Unsupported group kind all (expected exactly_one, at_least_one or at_most_one)")),
+        );
+    }
+
+    #[test]
+    fn builder_derive_groups_not_option() {
+        let actual = errbuilder(parse_quote! {
+            struct Foobar {
+                #[builder(group(auth, exactly_one))]
+                password: String,
+            }
+        });
+        assert_eq!(
+            actual,
+            Err(String::from("Field password: group member must be an Option field")),
+        );
+    }
+
+    #[test]
+    fn builder_derive_groups_default_struct() {
+        let actual = errbuilder(parse_quote! {
+            #[derive(Default)]
+            struct Foobar {
+                #[builder(group(auth, exactly_one))]
+                password: Option<String>,
+            }
+        });
+        assert_eq!(
+            actual,
+            Err(String::from("Field password: group is not supported with struct Default in Typestate mode")),
+        );
+        newbuilder(parse_quote! {
+            #[builder(mode=Result)]
+            #[derive(Default)]
+            struct Foobar {
+                #[builder(group(auth, exactly_one))]
+                password: Option<String>,
+            }
+        });
+    }
+
+    #[test]
+    fn builder_derive_explicit() {
+        let actual = newbuilder(parse_quote! {
+            struct Foobar {
+                #[builder(explicit)]
+                tls: Option<String>,
+            }
+        });
+        let tls = &actual.properties[0];
+        assert!(tls.option.is_enabled(), "tls.option");
+        assert!(tls.default.is_disabled(), "tls.default");
+        assert!(tls.is_required(), "tls.is_required()");
+    }
+
+    #[test]
+    fn builder_derive_explicit_not_option() {
+        let actual = errbuilder(parse_quote! {
+            struct Foobar {
+                #[builder(explicit)]
+                tls: String,
+            }
+        });
+        assert_eq!(
+            actual,
+            Err(String::from("Field tls: explicit is only supported for Option field")),
+        );
+    }
+
+    #[test]
+    fn builder_derive_explicit_struct_default() {
+        let actual = errbuilder(parse_quote! {
+            #[builder(Default)]
+            struct Foobar {
+                #[builder(explicit)]
+                tls: Option<String>,
+            }
+        });
+        assert_eq!(
+            actual,
+            Err(String::from("Field tls: explicit is not supported with struct Default")),
+        );
+    }
+
+    #[test]
+    fn builder_derive_tuple_name() {
+        let actual = newbuilder(parse_quote! {
+            struct Foobar(
+                #[builder(name = red)]
+                u8,
+                u8,
+            );
+        });
+        let red = &actual.properties[0];
+        assert_eq!("red", red.name, "red.name");
+        assert_eq!("red", red.setter().to_string(), "red.setter()");
+        assert_eq!("red", red.label(), "red.label()");
+        assert_eq!("0", red.id().to_string(), "red.id()");
+        let other = &actual.properties[1];
+        assert_eq!("set1", other.setter().to_string(), "other.setter()");
+        assert_eq!("1", other.label(), "other.label()");
+    }
+
+    #[test]
+    fn builder_derive_tuple_name_duplicate() {
+        let actual = errbuilder(parse_quote! {
+            struct Foobar(
+                #[builder(name = v1)]
+                u8,
+                u8,
+            );
+        });
+        assert_eq!(
+            actual,
+            Err(String::from("Field v1: name is already used by another field")),
+        );
+    }
+
+    #[test]
+    fn builder_derive_named_name() {
+        let actual = errbuilder(parse_quote! {
+            struct Foobar {
+                #[builder(name = bar)]
+                foo: u8,
+            }
+        });
+        assert_eq!(
+            actual,
+            Err(String::from("Field foo: name is only supported for tuple field")),
+        );
+    }
+
+    #[test]
+    fn builder_derive_panic_message() {
+        let actual = newbuilder(parse_quote! {
+            #[builder(mode=Panic, panic_message="Invalid:")]
+            struct Foobar {
+                foo: usize,
+            }
+        });
+        assert_eq!("Invalid:", actual.panic_header(), "panic_header()");
+        let actual = newbuilder(parse_quote! {
+            #[builder(mode=Panic)]
+            struct Foobar {
+                foo: usize,
+            }
+        });
+        assert_eq!("Unable to build Foobar:", actual.panic_header(), "panic_header()");
+    }
+
+    #[test]
+    fn builder_derive_panic_message_result() {
+        let actual = errbuilder(parse_quote! {
+            #[builder(mode=Result, panic_message="Invalid:")]
+            struct Foobar {
+                foo: usize,
+            }
+        });
+        assert_eq!(
+            actual,
+            Err(String::from("Unsupported panic_message for Result mode")),
+        );
+    }
+
+    #[test]
+    fn builder_derive_style_mutref() {
+        let actual = newbuilder(parse_quote! {
+            #[builder(mode=Result, style=MutRef)]
+            struct Foobar {
+                foo: usize,
+            }
+        });
+        assert_eq!(Mode::Result, actual.mode, "mode");
+        assert_eq!(Style::MutRef, actual.style, "style");
+    }
+
+    #[test]
+    fn builder_derive_style_mutref_typestate() {
+        let actual = errbuilder(parse_quote! {
+            #[builder(style=MutRef)]
+            struct Foobar {
+                foo: usize,
+            }
+        });
+        assert_eq!(
+            actual,
+            Err(String::from("Unsupported style MutRef for Typestate mode")),
+        );
+    }
+
+    #[test]
+    fn builder_derive_const() {
+        let actual = newbuilder(parse_quote! {
+            #[builder(const)]
+            #[derive(Default)]
+            struct Foobar {
+                foo: usize,
+                bar: Option<String>,
+            }
+        });
+        assert!(actual.konst.is_enabled(), "konst");
+        assert!(actual.default.is_disabled(), "default");
+        let foo = actual.properties.get_by_name("foo").unwrap();
+        assert!(foo.default.is_disabled(), "foo.default");
+        assert!(foo.into.is_disabled(), "foo.into");
+        assert!(foo.is_required(), "foo.is_required()");
+        let bar = actual.properties.get_by_name("bar").unwrap();
+        assert!(bar.default.is_disabled(), "bar.default");
+        assert!(bar.option.is_enabled(), "bar.option");
+    }
+
+    #[test]
+    fn builder_derive_const_result() {
+        let actual = errbuilder(parse_quote! {
+            #[builder(mode=Result, const)]
+            struct Foobar {
+                foo: usize,
+            }
+        });
+        assert_eq!(
+            actual,
+            Err(String::from("Unsupported const for Result mode")),
+        );
+    }
+
+    #[test]
+    fn builder_derive_const_field_into() {
+        let actual = errbuilder(parse_quote! {
+            #[builder(const)]
+            struct Foobar {
+                #[builder(Into)]
+                foo: usize,
+            }
+        });
+        assert_eq!(
+            actual,
+            Err(String::from("Field foo: Into is not supported by const builder")),
+        );
+    }
+
+    #[test]
+    fn builder_derive_overwrite() {
+        let actual = newbuilder(parse_quote! {
+            #[builder(overwrite)]
+            struct Foobar {
+                foo: usize,
+                #[builder(overwrite=!)]
+                bar: usize,
+            }
+        });
+        let foo = actual.properties.get_by_name("foo").unwrap();
+        assert!(foo.overwrite.is_enabled(), "foo.overwrite");
+        let bar = actual.properties.get_by_name("bar").unwrap();
+        assert!(bar.overwrite.is_disabled(), "bar.overwrite");
+    }
+
+    #[test]
+    fn builder_derive_const_overwrite() {
+        let actual = errbuilder(parse_quote! {
+            #[builder(const)]
+            struct Foobar {
+                #[builder(overwrite)]
+                foo: usize,
+            }
+        });
+        assert_eq!(
+            actual,
+            Err(String::from("Field foo: overwrite is not supported by const builder")),
+        );
+    }
+
+    #[test]
+    fn builder_derive_ordered_panic() {
+        let actual = errbuilder(parse_quote! {
+            #[builder(mode=Panic, ordered)]
+            struct Foobar {
+                foo: usize,
+            }
+        });
+        assert_eq!(
+            actual,
+            Err(String::from("Unsupported ordered for Panic mode")),
+        );
+    }
+
+    #[test]
+    fn builder_derive_ordered_group() {
+        let actual = errbuilder(parse_quote! {
+            #[builder(ordered)]
+            struct Foobar {
+                #[builder(group(auth, exactly_one))]
+                foo: Option<usize>,
+            }
+        });
+        assert_eq!(
+            actual,
+            Err(String::from("Field foo: group is not supported by ordered builder")),
+        );
+    }
+
+    #[test]
+    fn builder_derive_dynamic() {
+        let actual = newbuilder(parse_quote! {
+            #[builder(dynamic, const)]
+            struct Foobar {
+                foo: usize,
+            }
+        });
+        let dynamic = actual.dynamic_builder().expect("dynamic_builder()");
+        assert_eq!(dynamic.ident, format_ident!("FoobarDynamicBuilder"), "ident");
+        assert_eq!(dynamic.mode, Mode::Result, "mode");
+        assert!(dynamic.konst.is_undefined(), "konst");
+        assert!(dynamic.dynamic_builder().is_none(), "dynamic_builder()");
+    }
+
+    #[test]
+    fn builder_derive_dynamic_panic() {
+        let actual = errbuilder(parse_quote! {
+            #[builder(mode=Panic, dynamic)]
+            struct Foobar {
+                foo: usize,
+            }
+        });
+        assert_eq!(
+            actual,
+            Err(String::from("Unsupported dynamic for Panic mode")),
+        );
+    }
 }
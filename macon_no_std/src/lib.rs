@@ -0,0 +1,74 @@
+//! Check generated builders compile in a `no_std` crate (with `alloc`).
+//!
+//! Build it alone to also disable `std` in macon: `cargo build -p macon_no_std`
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::String;
+use macon::Builder;
+
+#[derive(Builder)]
+#[builder(mode=Typestate, withers, patch, dynamic)]
+pub struct Server {
+    pub name: String,
+    pub port: u16,
+    #[builder(group(proxy, at_most_one))]
+    pub http_proxy: Option<String>,
+    #[builder(group(proxy))]
+    pub socks_proxy: Option<String>,
+}
+
+#[derive(Builder)]
+#[builder(mode=Typestate, const)]
+pub struct Port(
+    u16,
+    Option<u16>,
+);
+
+#[derive(Builder)]
+#[builder(mode=Panic, derive(Clone, Debug))]
+pub struct Credentials {
+    pub name: String,
+    #[builder(group(auth, exactly_one))]
+    pub password: Option<String>,
+    #[builder(group(auth))]
+    pub token: Option<String>,
+}
+
+#[derive(Builder)]
+#[builder(mode=Result, style=MutRef, Default)]
+#[derive(Default)]
+pub struct Config {
+    pub name: String,
+    pub port: u16,
+    #[builder(group(output, at_most_one))]
+    pub file: Option<String>,
+    #[builder(group(output))]
+    pub url: Option<String>,
+}
+
+pub fn server() -> Server {
+    Server::builder()
+        .name("localhost")
+        .port(8080u16)
+        .build()
+}
+
+pub const PORT: Port = Port::builder()
+    .set(8080)
+    .none()
+    .build();
+
+pub fn credentials() -> Credentials {
+    Credentials::builder()
+        .name("admin")
+        .token("secret")
+        .build()
+}
+
+pub fn config() -> Result<Config, String> {
+    let mut builder = Config::builder();
+    builder.name("localhost");
+    builder.build()
+}
@@ -36,43 +36,97 @@
 //! #### struct
 //!
 //! * **`mode=<value>`** <br/>
-//! Change builder and associated `build()` function behavior. Supported values: [`Typestate`](#typestate-pattern-default) (_default_), [`Panic`](#panic-on-build) or [`Result`](#result-on-build).
+//!   Change builder and associated `build()` function behavior. Supported values: [`Typestate`](#typestate-pattern-default) (_default_), [`Panic`](#panic-on-build) or [`Result`](#result-on-build).
+//!
+//! * **`style=<value>`** <br/>
+//!   Change setters receiver. Supported values: `Owned` (_default_) or `MutRef` (only for `Panic` and `Result` modes). See ["Mutable reference setters"](#mutable-reference-setters).
 //!
 //! * **`Default=!`** <br/>
-//! Disable automatic [`Default`] derive detection for **struct**. See ["`Default` struct"](#default-struct).
+//!   Disable automatic [`Default`] derive detection for **struct**. See ["`Default` struct"](#default-struct).
 //!
 //! * **`Default`** <br/>
-//! Enforce [`Default`] support for **struct**. See ["`Default` struct"](#default-struct).
+//!   Enforce [`Default`] support for **struct**. See ["`Default` struct"](#default-struct).
 //!
 //! * **`Option=!`** (_deprecated. Use `fields(Option=!)` instead._)
 //!
 //! * **`Into=!`** (_deprecated. Use `fields(Into=!)` instead._)
 //!
+//! * **`withers`** <br/>
+//!   Generate `with_<field>()` functions on **struct**. See ["Withers"](#withers).
+//!
+//! * **`patch`** <br/>
+//!   Generate `<TargetStruct>Patch` struct for partial updates. See ["Patch"](#patch).
+//!
+//! * **`clone`** / **`clone=!`** <br/>
+//!   Enforce (or disable) `build_cloned()` generation on builder. Enabled by default when **struct** derives [`Clone`]. See ["Template builders"](#template-builders).
+//!
+//! * **`derive(<Trait>, ...)`** <br/>
+//!   Add derives to **builder** struct ([`Default`] is always derived). See ["Forward attributes"](#forward-attributes).
+//!
+//! * **`attr(<attribute>, ...)`** <br/>
+//!   Add attributes to **builder** struct. See ["Forward attributes"](#forward-attributes).
+//!
+//! * **`const`** <br/>
+//!   Generate `const fn` setters and `build()` (only for `Typestate` mode). See ["Const builders"](#const-builders).
+//!
+//! * **`overwrite`** <br/>
+//!   Keep setters available once **fields** are set (only relevant for `Typestate` mode). See ["Overwrite fields"](#overwrite-fields).
+//!
+//! * **`ordered`** <br/>
+//!   Only allow to call setters in **fields** declaration order (only for `Typestate` mode). See ["Ordered setters"](#ordered-setters).
+//!
+//! * **`dynamic`** <br/>
+//!   Generate runtime-checked `<TargetStruct>DynamicBuilder` and `into_dynamic()` conversion (only for `Typestate` mode). See ["Dynamic builders"](#dynamic-builders).
+//!
+//! * **`panic_message="<header>"`** <br/>
+//!   Replace header of `build()` panic message (only for `Panic` mode). See ["Panic on `build()`"](#panic-on-build).
+//!
+//! * **`crate=<path>`** <br/>
+//!   Change path used by generated code to refer to this crate (_default_: `::macon`). Useful when it's re-exported by another crate.
+//!
 //! * **`fields(Option=!)`** <br/>
-//! Disable automatic [`Option`] detection for **fields**. See ["`Option` fields"](#option-fields).
+//!   Disable automatic [`Option`] detection for **fields**. See ["`Option` fields"](#option-fields).
 //!
 //! * **`fields(Default=!)`** <br/>
-//! Disable automatic [`Default`] detection for **fields**. See ["`Default` fields"](#default-fields).
+//!   Disable automatic [`Default`] detection for **fields**. See ["`Default` fields"](#default-fields).
 //!
 //! * **`fields(Into=!)`** <br/>
-//! Disable [`Into`] for **fields**. See ["`Into` argument"](#into-argument).
+//!   Disable [`Into`] for **fields**. See ["`Into` argument"](#into-argument).
 //!
 //! #### field
 //!
 //! * **`Option=!`** <br/>
-//! Disable automatic [`Option`] detection for given field. Generated setter will rely on declared field type. See ["`Option` fields"](#option-fields).
+//!   Disable automatic [`Option`] detection for given field. Generated setter will rely on declared field type. See ["`Option` fields"](#option-fields).
 //!
 //! * **`Option=WrappedType`** <br/>
-//! Enforce [`Option`] support for given field. Generated setter will rely on `WrappedType`. See ["`Option` fields"](#option-fields).
+//!   Enforce [`Option`] support for given field. Generated setter will rely on `WrappedType`. See ["`Option` fields"](#option-fields).
 //!
 //! * **`Default=!`** <br/>
-//! Disable automatic [`Default`] detection for given field. See ["`Default` fields"](#default-fields).
+//!   Disable automatic [`Default`] detection for given field. See ["`Default` fields"](#default-fields).
 //!
 //! * **`Default`** <br/>
-//! Enforce [`Default`] support for given field. See ["`Default` fields"](#default-fields).
+//!   Enforce [`Default`] support for given field. See ["`Default` fields"](#default-fields).
 //!
 //! * **`Into=!`** <br/>
-//! Disable [`Into`] for setter. See ["`Into` argument"](#into-argument).
+//!   Disable [`Into`] for setter. See ["`Into` argument"](#into-argument).
+//!
+//! * **`setter_attr(<attribute>, ...)`** <br/>
+//!   Add attributes to all setters of given field. See ["Forward attributes"](#forward-attributes).
+//!
+//! * **`sensitive`** <br/>
+//!   Redact field value from builder [`Debug`] output. See ["Debug builders"](#debug-builders).
+//!
+//! * **`overwrite`** / **`overwrite=!`** <br/>
+//!   Enforce (or disable) setters availability once given field is set. See ["Overwrite fields"](#overwrite-fields).
+//!
+//! * **`name=<ident>`** <br/>
+//!   Name tuple field setters (and getters) with `<ident>` instead of its ordinal. See ["Tuple"](#tuple).
+//!
+//! * **`explicit`** <br/>
+//!   Require [`Option`] field to be explicitly set, either to a value or to [`None`], before building. See ["`Option` fields"](#option-fields).
+//!
+//! * **`group(<name>, <kind>)`** <br/>
+//!   Add [`Option`] field to a group constrained by `<kind>` (`exactly_one`, `at_least_one` or `at_most_one`). Kind can be omitted when declared by another member. See ["Field groups"](#field-groups).
 //!
 //! ### Features
 //!
@@ -93,6 +147,30 @@
 //! * Build function `build()` when all properties has been set
 //! * Each property setter function as long as property haven't been set
 //!
+//! Fields are stored the same way as in [`Panic`](#panic-on-build)/[`Result`](#result-on-build) modes, and generic parameters only
+//! carry state of tracked fields: either [`macon::Unset`](crate::Unset) or [`macon::Set`](crate::Set). Tracked fields are mandatory
+//! ones and [group](#field-groups) members (or all fields of [tuple](#tuple), [ordered](#ordered-setters) and [const](#const-builders)
+//! builders). Setters of other fields ([`Option`](#option-fields) and [`Default`](#default-fields) ones) stay available once set, last
+//! value being kept. All setters are declared once in a single `impl` block (instead of once per builder state), which reduces
+//! generated code (and documentation).
+//!
+//! Misuses are reported with readable compilation errors, e.g. `` field `path` of `MyType` must be set before calling build() ``
+//! or `` field `path` was already set ``:
+//!
+//! ```compile_fail
+//! # #[macro_use] extern crate macon;
+//! # use std::path::PathBuf;
+//! #[derive(Builder)]
+//! struct MyType {
+//!   integer: i32,
+//!   path: PathBuf,
+//! }
+//!
+//! MyType::builder()
+//!   .integer(42)
+//!   .build();
+//! ```
+//!
 //! Optionally, you can set it explictly:
 //!
 //! ```
@@ -154,6 +232,23 @@
 //!     .build();
 //! ```
 //!
+//! Panic is reported at `build()` (or [`From::from()`](#implement-into)) call location. Message starts with a header naming target
+//! type (`Unable to build MyType:`), followed by one line per error. Header can be replaced with `panic_message` setting:
+//!
+//! ```should_panic
+//! # #[macro_use] extern crate macon;
+//! # use std::path::PathBuf;
+//! #[derive(Builder)]
+//! #[builder(mode=Panic, panic_message="Invalid server configuration:")]
+//! struct Server {
+//!   host: PathBuf,
+//! }
+//!
+//! // panics with "Invalid server configuration:\nField host is missing"
+//! let _server: Server = Server::builder()
+//!     .build();
+//! ```
+//!
 //! #### Result on `build()`
 //!
 //! Blueprints:
@@ -234,7 +329,7 @@
 //!     .build();
 //! ```
 //!
-//! Only for [`Typestate` mode](#typestate-pattern-default), you can chain `set()`, [`none()`](#option-fields), [`keep()`](#default-struct) and [`default()`](#default-fields) calls to assign values in order:
+//! In [`Typestate` mode](#typestate-pattern-default), you can chain `set()`, [`none()`](#option-fields), [`keep()`](#default-struct) and [`default()`](#default-fields) calls to assign values in order:
 //!
 //! ```
 //! # #[macro_use] extern crate macon;
@@ -276,6 +371,122 @@
 //!     .build();
 //! ```
 //!
+//! In [`Panic`](#panic-on-build) and [`Result`](#result-on-build) modes, same calls move a cursor stored in builder. As next
+//! position is only known at runtime, `set()` argument must have exact type of a field (no [`Into`] conversion). Values which
+//! can't be assigned at their position (wrong type, not an `Option` field for `none()`, ...) or past last field are reported
+//! by `build()`:
+//!
+//! ```
+//! # #[macro_use] extern crate macon;
+//! #[derive(Builder)]
+//! #[builder(mode=Result)]
+//! #[derive(Debug)]
+//! struct MyTuple(
+//!   i32,
+//!   Option<String>,
+//!   String,
+//! );
+//!
+//! let mytuple = MyTuple::builder()
+//!     .set(42)
+//!     .none()
+//!     .set(String::from("foobar"))
+//!     .build();
+//! assert!(mytuple.is_ok());
+//!
+//! let error = MyTuple::builder()
+//!     .set(42)
+//!     .set(String::from("foobar"))
+//!     .none()
+//!     .build();
+//! assert_eq!(
+//!     Err(String::from("Field 2 can't be assigned from positional setter")),
+//!     error.map(|_| ()),
+//! );
+//! ```
+//!
+//! When the same type is written differently by several fields (e.g. a type alias and its target, or `String` and
+//! `::std::string::String`), `set()` can't infer which of them is meant. Give position of first field written like expected one:
+//!
+//! ```
+//! # #[macro_use] extern crate macon;
+//! type Port = u16;
+//!
+//! #[derive(Builder)]
+//! #[builder(mode=Panic)]
+//! struct Ports(
+//!   u16,
+//!   Port,
+//! );
+//!
+//! let _ports: Ports = Ports::builder()
+//!     .set::<_, 0>(8080)
+//!     .set::<_, 1>(9090)
+//!     .build();
+//! ```
+//!
+//! Tuple fields can be given a name with `name=<ident>` setting. Setters are then named after it (instead of `set<ordinal>()`), as
+//! well as errors about missing fields. Positional `set()` calls are still available:
+//!
+//! ```
+//! # #[macro_use] extern crate macon;
+//! #[derive(Builder)]
+//! #[builder(mode=Panic)]
+//! struct Rgb(
+//!   #[builder(name = red)]
+//!   u8,
+//!   #[builder(name = green)]
+//!   u8,
+//!   #[builder(name = blue)]
+//!   u8,
+//! );
+//!
+//! // Builder signature
+//! # struct Builder;
+//! impl Builder {
+//!   fn red(self, value: u8) -> Self
+//! # { unimplemented!(); }
+//!   fn green(self, value: u8) -> Self
+//! # { unimplemented!(); }
+//!   fn blue(self, value: u8) -> Self
+//! # { unimplemented!(); }
+//!   fn build(self) -> Rgb
+//! # { unimplemented!(); }
+//! }
+//!
+//! let _rgb: Rgb = Rgb::builder()
+//!     .red(255)
+//!     .green(128)
+//!     .blue(0)
+//!     .build();
+//! ```
+//!
+//! #### Ordered setters
+//!
+//! Only for [`Typestate` mode](#typestate-pattern-default), `ordered` setting restricts **named** struct setters to fields declaration
+//! order. It provides a constructor-like API with named arguments. Optional fields must be explicitly skipped (e.g. with
+//! [`<field>_none()`](#option-fields)), unless all following fields are optional too:
+//!
+//! ```
+//! # #[macro_use] extern crate macon;
+//! # use std::path::PathBuf;
+//! #[derive(Builder)]
+//! #[builder(ordered)]
+//! struct Server {
+//!   host: PathBuf,
+//!   name: Option<String>,
+//!   path: PathBuf,
+//! }
+//!
+//! let _server: Server = Server::builder()
+//!     .host("localhost")
+//!     .name_none()
+//!     .path("/var/www")
+//!     .build();
+//! ```
+//!
+//! It's not supported with [field groups](#field-groups) nor [`overwrite`](#overwrite-fields).
+//!
 //! #### `Into` argument
 //!
 //! Blueprints:
@@ -386,6 +597,419 @@
 //!     .into();
 //! ```
 //!
+//! #### Back to builder
+//!
+//! Target struct can be converted back into a builder with all fields set, using `into_builder()` or [`From`] implementation.
+//! If target struct derives [`Clone`], `to_builder()` is also available:
+//!
+//! ```
+//! # #[macro_use] extern crate macon;
+//! # use std::path::PathBuf;
+//! #[derive(Builder)]
+//! #[builder(mode=Panic)]
+//! #[derive(Clone,Debug,PartialEq,)]
+//! struct Server {
+//!   root: PathBuf,
+//!   port: u16,
+//! }
+//!
+//! let server = Server::builder()
+//!   .root("/var/www")
+//!   .port(80u16)
+//!   .build();
+//!
+//! let updated = server
+//!   .to_builder()
+//!   .port(8080u16)
+//!   .build();
+//!
+//! assert_eq!(PathBuf::from("/var/www"), updated.root);
+//! assert_eq!(8080, updated.port);
+//! ```
+//!
+//! In [`Typestate` mode](#typestate-pattern-default), returned builder is in the state where all fields are set.
+//! It isn't available when struct has an `exactly_one` [group](#field-groups) with many members.
+//!
+//! #### Template builders
+//!
+//! Builders implement [`Clone`] when all their fields do. A partially configured builder can act as a template using `build_cloned()`,
+//! which builds a new instance without consuming the builder. It's generated when **struct** derives [`Clone`] or when `#[builder(clone)]` is set:
+//!
+//! ```
+//! # #[macro_use] extern crate macon;
+//! # use std::path::PathBuf;
+//! #[derive(Builder)]
+//! #[builder(clone)]
+//! struct Worker {
+//!   root: PathBuf,
+//!   port: u16,
+//! }
+//!
+//! let template = Worker::builder()
+//!   .root("/var/www");
+//!
+//! let first = template.clone().port(8080u16).build();
+//! let second = template.clone().port(8081u16).build();
+//! let default = template.port(8082u16);
+//! let others: Vec<Worker> = (0..4).map(|_| default.build_cloned()).collect();
+//!
+//! assert_eq!(8080, first.port);
+//! assert_eq!(8081, second.port);
+//! assert_eq!(4, others.len());
+//! ```
+//!
+//! #### Forward attributes
+//!
+//! Extra derives and attributes can be added to builder struct using `derive(...)` and `attr(...)`, and to field setters using `setter_attr(...)`:
+//!
+//! ```
+//! # #[macro_use] extern crate macon;
+//! # use std::path::PathBuf;
+//! #[derive(Builder)]
+//! #[builder(derive(Clone, Debug, PartialEq), attr(must_use))]
+//! struct Server {
+//!   #[builder(setter_attr(inline, must_use))]
+//!   root: PathBuf,
+//!   port: u16,
+//! }
+//!
+//! let builder = Server::builder()
+//!   .root("/var/www");
+//! assert_eq!(builder.clone(), builder);
+//! ```
+//!
+//! #### Debug builders
+//!
+//! Builders implement [`Debug`] when all field types do, showing each field state (`Undefined`, `Keep`, `Default` or `Set(<value>)`).
+//! Values of `sensitive` fields are redacted:
+//!
+//! ```
+//! # #[macro_use] extern crate macon;
+//! #[derive(Builder)]
+//! #[builder(mode=Result)]
+//! struct Credentials {
+//!   login: String,
+//!   #[builder(sensitive)]
+//!   password: String,
+//! }
+//!
+//! let builder = Credentials::builder()
+//!   .password("secret");
+//! assert_eq!(
+//!   "CredentialsBuilder { login: Default, password: Set(***) }",
+//!   format!("{:?}", builder),
+//! );
+//! ```
+//!
+//! #### Generic code
+//!
+//! Target structs implement [`Buildable`] and builders (in a buildable state) implement [`Builder`](trait@Builder), allowing
+//! to write generic code over any derived type:
+//!
+//! ```
+//! # #[macro_use] extern crate macon;
+//! use macon::{Buildable, Builder};
+//!
+//! #[derive(Builder)]
+//! #[builder(mode=Result)]
+//! #[derive(Default)]
+//! struct Server {
+//!   port: u16,
+//! }
+//!
+//! fn fixture<T>() -> <T::Builder as Builder>::Output
+//! where
+//!   T: Buildable,
+//!   T::Builder: Builder<Target=T>,
+//! {
+//!   T::builder().build()
+//! }
+//!
+//! let server: Server = fixture::<Server>().unwrap();
+//! assert_eq!(0, server.port);
+//! ```
+//!
+//! With `style=MutRef`, [`Builder`](trait@Builder) is implemented for builder reference (as owned builder `build()` borrows it, an
+//! implementation consuming it would take precedence on method calls through `&mut` references returned by setters). Generic code
+//! bounds it with a higher-ranked lifetime:
+//!
+//! ```
+//! # #[macro_use] extern crate macon;
+//! use macon::{Buildable, Builder};
+//!
+//! #[derive(Builder)]
+//! #[builder(mode=Result, style=MutRef)]
+//! #[derive(Default)]
+//! struct Server {
+//!   port: u16,
+//! }
+//!
+//! fn make<T, O>(fill: impl FnOnce(&mut T::Builder)) -> O
+//! where
+//!   T: Buildable,
+//!   for<'a> &'a T::Builder: Builder<Target=T, Output=O>,
+//! {
+//!   let mut builder = T::builder();
+//!   fill(&mut builder);
+//!   Builder::build(&builder)
+//! }
+//!
+//! let server: Server = make::<Server, _>(|builder| {
+//!   builder.port(8080u16);
+//! }).unwrap();
+//! assert_eq!(8080, server.port);
+//! ```
+//!
+//! #### Naming builder types
+//!
+//! In [`Typestate` mode](#typestate-pattern-default), builder type depends on fields state. To write functions over builders without
+//! spelling out its generic parameters, type aliases and traits (only for [tracked](#typestate-pattern-default) fields) are generated:
+//!
+//! * `<Builder>Empty` type alias: builder with no field set
+//! * `<Builder>Complete` type alias: builder with all fields set (unless an `exactly_one` [group](#field-groups) has many members)
+//! * `<Builder>Has<Field>` trait: implemented by builders where field is set
+//! * `<Builder>Needs<Field>` trait: implemented by builders where field can be set. It provides field value setter, returning associated
+//!   type `With<Field>` (not generated for [ordered](#ordered-setters) named structs)
+//!
+//! ```
+//! # #[macro_use] extern crate macon;
+//! # use std::path::PathBuf;
+//! #[derive(Builder)]
+//! struct Server {
+//!   host: PathBuf,
+//!   #[builder(Into=!, Default=!)]
+//!   port: u16,
+//! }
+//!
+//! fn with_defaults<B: ServerBuilderNeedsPort>(builder: B) -> B::WithPort {
+//!   builder.port(8080)
+//! }
+//!
+//! let empty: ServerBuilderEmpty = Server::builder();
+//! let complete: ServerBuilderComplete = with_defaults(empty).host("localhost");
+//! assert_eq!(8080, complete.build().port);
+//! ```
+//!
+//! #### Dynamic builders
+//!
+//! When fields to set are only known at runtime (e.g. from a parsed file), `dynamic` setting generates, along
+//! [`Typestate`](#typestate-pattern-default) builder, a `<TargetStruct>DynamicBuilder` behaving like [`Result` mode](#result-on-build)
+//! builder. Typestate builder, in any state, can be converted with `into_dynamic()`:
+//!
+//! ```
+//! # #[macro_use] extern crate macon;
+//! # use std::path::PathBuf;
+//! #[derive(Builder)]
+//! #[builder(dynamic)]
+//! struct Server {
+//!   host: PathBuf,
+//!   path: PathBuf,
+//! }
+//!
+//! let mut builder: ServerDynamicBuilder = Server::builder()
+//!   .host("localhost")
+//!   .into_dynamic();
+//! for (key, value) in [("path", "/var/www")] {
+//!   builder = match key {
+//!     "path" => builder.path(value),
+//!     _ => builder,
+//!   };
+//! }
+//! let server: Result<Server, String> = builder.build();
+//! assert!(server.is_ok());
+//! ```
+//!
+//! #### Overwrite fields
+//!
+//! In [`Typestate` mode](#typestate-pattern-default), a [tracked](#typestate-pattern-default) field can only be set once. With `overwrite`
+//! setting (on struct or field), setters stay available once field is set, while `build()` still requires it to be set. It allows to apply
+//! defaults then let callers override them:
+//!
+//! ```
+//! # #[macro_use] extern crate macon;
+//! # use std::path::PathBuf;
+//! #[derive(Builder)]
+//! #[builder(overwrite)]
+//! struct Server {
+//!   host: PathBuf,
+//!   #[builder(Into=!, Default=!)]
+//!   port: u16,
+//! }
+//!
+//! fn with_defaults(builder: ServerBuilder) -> ServerBuilderComplete {
+//!   builder
+//!     .host("localhost")
+//!     .port(80)
+//! }
+//!
+//! let server = with_defaults(Server::builder())
+//!   .port(8080)
+//!   .build();
+//!
+//! assert_eq!(8080, server.port);
+//! ```
+//!
+//! `Panic` and `Result` modes always allow to set a field many times. It's not supported by [const builders](#const-builders).
+//!
+//! #### Const builders
+//!
+//! `#[builder(const)]` generates `const fn` for `builder()`, setters and `build()`, allowing to build values in `const` and `static` items:
+//!
+//! ```
+//! # #[macro_use] extern crate macon;
+//! #[derive(Builder)]
+//! #[builder(const)]
+//! struct Limits {
+//!   max: usize,
+//!   timeout: Option<u64>,
+//! }
+//!
+//! static DEFAULT_LIMITS: Limits = Limits::builder()
+//!   .max(10)
+//!   .build();
+//!
+//! assert_eq!(10, DEFAULT_LIMITS.max);
+//! assert_eq!(None, DEFAULT_LIMITS.timeout);
+//! ```
+//!
+//! As traits can't be called in `const` context, it's only supported by `Typestate` mode, and:
+//! * [`Into`] is disabled for all fields,
+//! * [`Default`] is disabled for struct and fields (unset [`Option`] fields are still `None`).
+//!
+//! #### `no_std`
+//!
+//! Generated code only relies on `core` and `alloc`. Disable default features to use it in `no_std` crates:
+//!
+//! * **`std`** (_default_): enable `alloc`.
+//! * **`alloc`**: required by `Panic` and `Result` builders, and by `missing_fields()` function.
+//!
+//! ```toml
+//! [dependencies]
+//! macon = { version = "*", default-features = false, features = ["alloc"] }
+//! ```
+//!
+//! #### Withers
+//!
+//! For immutable value types, `#[builder(withers)]` also generates `with_<field>()` functions on target struct, returning an updated copy.
+//! They follow same [`Into`](#into-argument) and [`Option`](#option-fields) rules as setters (including `with_<field>_none()` and `with_<field>_optional()`):
+//!
+//! ```
+//! # #[macro_use] extern crate macon;
+//! # use std::path::PathBuf;
+//! #[derive(Builder)]
+//! #[builder(withers)]
+//! struct Server {
+//!   root: PathBuf,
+//!   port: u16,
+//!   name: Option<String>,
+//! }
+//!
+//! let server = Server::builder()
+//!   .root("/var/www")
+//!   .port(80u16)
+//!   .build()
+//!   .with_port(8080u16)
+//!   .with_name("www");
+//!
+//! assert_eq!(8080, server.port);
+//! assert_eq!(Some(String::from("www")), server.name);
+//! ```
+//!
+//! #### Mutable reference setters
+//!
+//! In [`Panic`](#panic-on-build) and [`Result`](#result-on-build) modes, `style=MutRef` makes setters borrow builder mutably
+//! (`&mut self -> &mut Self`) instead of consuming it. `build()` also borrows builder and clones values (fields must implement [`Clone`]):
+//!
+//! ```
+//! # #[macro_use] extern crate macon;
+//! # use std::path::PathBuf;
+//! #[derive(Builder)]
+//! #[builder(mode=Result, style=MutRef)]
+//! struct Server {
+//!   root: PathBuf,
+//!   port: u16,
+//! }
+//!
+//! let mut builder = Server::builder();
+//! builder.root("/var/www");
+//! for arg in ["--port=8080"] {
+//!   if let Some(port) = arg.strip_prefix("--port=") {
+//!     builder.port(port.parse::<u16>().unwrap());
+//!   }
+//! }
+//!
+//! let server = builder.build().unwrap();
+//! assert_eq!(8080, server.port);
+//! ```
+//!
+//! #### Merge builders
+//!
+//! In [`Panic`](#panic-on-build) and [`Result`](#result-on-build) modes, partially filled builders can be layered using `merge()`:
+//! fields set in argument override those of current builder. Unset, [kept](#default-struct) or [defaulted](#default-fields) fields
+//! in argument leave current builder untouched. `or()` applies the opposite precedence:
+//!
+//! ```
+//! # #[macro_use] extern crate macon;
+//! # use std::path::PathBuf;
+//! #[derive(Builder)]
+//! #[builder(mode=Result)]
+//! struct Server {
+//!   root: PathBuf,
+//!   port: u16,
+//! }
+//!
+//! let file = Server::builder()
+//!   .root("/var/www")
+//!   .port(80u16);
+//! let cli = Server::builder()
+//!   .port(8080u16);
+//!
+//! let server = file.merge(cli).build().unwrap();
+//! assert_eq!(PathBuf::from("/var/www"), server.root);
+//! assert_eq!(8080, server.port);
+//!
+//! let server = Server::builder()
+//!   .port(8080u16)
+//!   .or(Server::builder().root("/var/www").port(80u16))
+//!   .build()
+//!   .unwrap();
+//! assert_eq!(8080, server.port);
+//! ```
+//!
+//! #### Patch
+//!
+//! `#[builder(patch)]` generates a `<TargetStruct>Patch` struct holding an optional value for each field. It can be filled
+//! with same setters as builder, layered using `merge()` (values from argument take precedence) and finally applied to
+//! an existing instance using `apply()`. Extra derives from [`derive(...)`](#forward-attributes) are also added to patch struct:
+//!
+//! ```
+//! # #[macro_use] extern crate macon;
+//! # use std::path::PathBuf;
+//! #[derive(Builder)]
+//! #[builder(patch)]
+//! struct Server {
+//!   root: PathBuf,
+//!   port: u16,
+//!   name: Option<String>,
+//! }
+//!
+//! let mut server = Server::builder()
+//!   .root("/var/www")
+//!   .port(80u16)
+//!   .build();
+//!
+//! let file = ServerPatch::default()
+//!   .port(8080u16)
+//!   .name("www");
+//! let cli = ServerPatch::default()
+//!   .port(9090u16);
+//! file.merge(cli).apply(&mut server);
+//!
+//! assert_eq!(PathBuf::from("/var/www"), server.root);
+//! assert_eq!(9090, server.port);
+//! assert_eq!(Some(String::from("www")), server.name);
+//! ```
+//!
 //! #### `Option` fields
 //!
 //! Blueprints:
@@ -544,6 +1168,41 @@
 //! assert_eq!(Some(String::from("any")), built.discretionary);
 //! ```
 //!
+//! When leaving an [`Option`] field unset is not a safe choice, `explicit` setting makes it required: either
+//! value setter, `<field>_none()` or `<field>_optional()` must be called before building. Missing decision is a compilation
+//! error in [`Typestate` mode](#typestate-pattern-default) (or an error/panic in [`Result`](#result-on-build)/[`Panic`](#panic-on-build) modes):
+//!
+//! ```compile_fail
+//! # #[macro_use] extern crate macon;
+//! #[derive(Builder)]
+//! struct Server {
+//!   host: String,
+//!   #[builder(explicit)]
+//!   tls: Option<String>,
+//! }
+//!
+//! // error: field `tls` of `Server` must be set (or explicitly unset with `tls_none()`) before calling build()
+//! let built = Server::builder()
+//!   .host("localhost")
+//!   .build();
+//! ```
+//!
+//! ```
+//! # #[macro_use] extern crate macon;
+//! # #[derive(Builder)]
+//! # struct Server {
+//! #   host: String,
+//! #   #[builder(explicit)]
+//! #   tls: Option<String>,
+//! # }
+//! let built = Server::builder()
+//!   .host("localhost")
+//!   .tls_none()
+//!   .build();
+//!
+//! assert_eq!(None, built.tls);
+//! ```
+//!
 //! #### `Default` struct
 //!
 //! Blueprints:
@@ -838,6 +1497,215 @@
 //! ```
 //!
 //!
+//! #### Field groups
+//!
+//! [`Option`] fields can be grouped with `#[builder(group(<name>, <kind>))]` to constrain how many of them are set:
+//!
+//! * `exactly_one`: one and only one member must be set,
+//! * `at_least_one`: one or more members must be set,
+//! * `at_most_one`: no more than one member can be set.
+//!
+//! In [`Panic`](#panic-on-build) and [`Result`](#result-on-build) modes, constraints are checked by `build()` (a member counts as set when its value is [`Some`]):
+//!
+//! ```
+//! # #[macro_use] extern crate macon;
+//! #[derive(Builder)]
+//! #[builder(mode=Result)]
+//! struct Credentials {
+//!   #[builder(group(auth, exactly_one))]
+//!   password: Option<String>,
+//!   #[builder(group(auth))]
+//!   token: Option<String>,
+//! }
+//!
+//! let built = Credentials::builder()
+//!   .password("secret")
+//!   .token("secret")
+//!   .build();
+//!
+//! assert_eq!(
+//!   Err(String::from("Group auth requires exactly one of password, token to be set")),
+//!   built.map(|_| ()),
+//! );
+//! ```
+//!
+//! With [`Default` struct](#default-struct), members kept from default instance are counted too.
+//!
+//! In [`Typestate` mode](#typestate-pattern-default), constraints are checked at compile time. Setting a member of an `exactly_one`
+//! or `at_most_one` group removes setters of other members. `<field>_none()` and `<field>_default()` leave member unset for
+//! the group (other members can still be set):
+//!
+//! ```compile_fail
+//! # #[macro_use] extern crate macon;
+//! #[derive(Builder)]
+//! struct Credentials {
+//!   #[builder(group(auth, exactly_one))]
+//!   password: Option<String>,
+//!   #[builder(group(auth))]
+//!   token: Option<String>,
+//! }
+//!
+//! Credentials::builder()
+//!   .password("secret")
+//!   .token("secret")
+//!   .build();
+//! ```
+//!
+//! ```compile_fail
+//! # #[macro_use] extern crate macon;
+//! #[derive(Builder)]
+//! struct Credentials {
+//!   #[builder(group(auth, at_least_one))]
+//!   password: Option<String>,
+//!   #[builder(group(auth))]
+//!   token: Option<String>,
+//! }
+//!
+//! Credentials::builder()
+//!   .build();
+//! ```
+//!
+//! ```
+//! # #[macro_use] extern crate macon;
+//! # #[derive(Builder)]
+//! # struct Credentials {
+//! #   #[builder(group(auth, exactly_one))]
+//! #   password: Option<String>,
+//! #   #[builder(group(auth))]
+//! #   token: Option<String>,
+//! # }
+//! let built = Credentials::builder()
+//!   .password_none()
+//!   .token("secret")
+//!   .build();
+//!
+//! assert_eq!(None, built.password);
+//! ```
+//!
+//! As its value is only known at runtime, `<field>_optional()` is only available for `at_most_one` members (and counted as set):
+//!
+//! ```compile_fail
+//! # #[macro_use] extern crate macon;
+//! #[derive(Builder)]
+//! struct Credentials {
+//!   #[builder(group(auth, exactly_one))]
+//!   password: Option<String>,
+//!   #[builder(group(auth))]
+//!   token: Option<String>,
+//! }
+//!
+//! Credentials::builder()
+//!   .password_optional(Some("secret"))
+//!   .build();
+//! ```
+//!
+//! For the same reason, positional setters of [tuples](#tuple) (`set()`, `none()`, ...) stop at first group member.
+//! Use `set<ordinal>()` (or [named](#tuple) setters) instead:
+//!
+//! ```compile_fail
+//! # #[macro_use] extern crate macon;
+//! #[derive(Builder)]
+//! struct Credentials(
+//!   String,
+//!   #[builder(group(auth, at_least_one))]
+//!   Option<String>,
+//!   #[builder(group(auth))]
+//!   Option<String>,
+//! );
+//!
+//! Credentials::builder()
+//!   .set("admin")
+//!   .set("secret")
+//!   .build();
+//! ```
+//!
+//! ```
+//! # #[macro_use] extern crate macon;
+//! # #[derive(Builder)]
+//! # struct Credentials(
+//! #   String,
+//! #   #[builder(group(auth, at_least_one))]
+//! #   Option<String>,
+//! #   #[builder(group(auth))]
+//! #   Option<String>,
+//! # );
+//! let built = Credentials::builder()
+//!   .set("admin")
+//!   .set1("secret")
+//!   .build();
+//!
+//! assert_eq!(Some(String::from("secret")), built.1);
+//! ```
+//!
+//! As default instance is only known at runtime, groups aren't supported with [`Default` struct](#default-struct) in
+//! `Typestate` mode (disable it with `#[builder(Default=!)]`, or use another mode).
+//!
+//! #### Introspection
+//!
+//! Builders can tell which fields have been set:
+//!
+//! * `is_<field>_set()` (or `is_set<ordinal>()` for tuples) checks if a value has been provided to builder,
+//! * `missing_fields()` lists mandatory fields not set yet (name, or ordinal for tuples).
+//!
+//! `get_<field>()` (or `get<ordinal>()` for tuples) also borrows value provided to builder (if any):
+//!
+//! ```
+//! # #[macro_use] extern crate macon;
+//! # use std::path::PathBuf;
+//! #[derive(Builder)]
+//! #[builder(mode=Result)]
+//! struct Server {
+//!   root: PathBuf,
+//!   port: u16,
+//! }
+//!
+//! // Builder signature
+//! # struct Builder;
+//! impl Builder {
+//!   fn get_root(&self) -> Option<&PathBuf>
+//! # { unimplemented!(); }
+//!   fn is_root_set(&self) -> bool
+//! # { unimplemented!(); }
+//!   fn missing_fields(&self) -> Vec<&'static str>
+//! # { unimplemented!(); }
+//! }
+//!
+//! let mut builder = Server::builder()
+//!   .port(80u16);
+//! assert_eq!(vec!["root"], builder.missing_fields());
+//!
+//! if ! builder.is_root_set() {
+//!   builder = builder.root("/var/www");
+//! }
+//! assert_eq!(Some(&PathBuf::from("/var/www")), builder.get_root());
+//! assert!(builder.missing_fields().is_empty());
+//! ```
+//!
+//! In [`Typestate` mode](#typestate-pattern-default), set state of [tracked](#typestate-pattern-default) fields is known at compile
+//! time. Their `is_<field>_set()` are `const` functions and [`macon::FieldState`](crate::FieldState) trait is implemented by field
+//! typestate (either [`macon::Unset`](crate::Unset) or [`macon::Set`](crate::Set)):
+//!
+//! ```
+//! # #[macro_use] extern crate macon;
+//! # use std::path::PathBuf;
+//! #[derive(Builder)]
+//! struct Server {
+//!   root: PathBuf,
+//!   port: u16,
+//! }
+//!
+//! fn is_root_set<ROOT: macon::FieldState>(_: &ServerBuilder<ROOT>) -> bool {
+//!   ROOT::IS_SET
+//! }
+//!
+//! let builder = Server::builder()
+//!   .port(8080u16);
+//!
+//! assert!(builder.is_port_set());
+//! assert!(! is_root_set(&builder));
+//! assert_eq!(vec!["root"], builder.missing_fields());
+//! ```
+//!
 //! [tests]: https://github.com/loganmzz/macon-rs/tree/main/tests
 //! [blueprint_panic_default_field.rs]: https://github.com/loganmzz/macon-rs/blob/main/tests/blueprint_panic_default_field.rs
 //! [blueprint_panic_default_struct.rs]: https://github.com/loganmzz/macon-rs/blob/main/tests/blueprint_panic_default_struct.rs
@@ -856,5 +1724,7 @@
 //! [blueprint_typestate_tuple.rs]: https://github.com/loganmzz/macon-rs/blob/main/tests/blueprint_typestate_tuple.rs
 //!
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
 pub use ::macon_derive::*;
 pub use ::macon_api::*;
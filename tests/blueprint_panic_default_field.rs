@@ -83,15 +83,16 @@ impl StructNamedBuilder {
     }
 
     // impl_builder / impl_builder_build
+    #[track_caller]
     pub fn build(self) -> StructNamed {
-        let mut errors: ::std::vec::Vec<::std::string::String> = ::std::vec![];
+        let mut errors: ::macon::__private::Vec<::macon::__private::String> = ::macon::__private::vec![];
 
         if self.mandatory.is_undefined() {
             errors.push("Field mandatory is missing".into());
         }
 
         if !errors.is_empty() {
-            panic!("{}", errors.join("\n"));
+            panic!("{}\n{}", "Unable to build StructNamed:", errors.join("\n"));
         } else {
             StructNamed {
                 id: self.id.unwrap(),
@@ -105,6 +106,7 @@ impl StructNamedBuilder {
 
 // impl_builder / impl_builder_from
 impl ::core::convert::From<StructNamedBuilder> for StructNamed {
+    #[track_caller]
     fn from(builder: StructNamedBuilder) -> Self {
         builder.build()
     }
@@ -170,15 +172,16 @@ impl StructTupleBuilder {
     }
 
     // impl_builder / impl_builder_build
+    #[track_caller]
     pub fn build(self) -> StructTuple {
-        let mut errors: ::std::vec::Vec<::std::string::String> = ::std::vec![];
+        let mut errors: ::macon::__private::Vec<::macon::__private::String> = ::macon::__private::vec![];
 
         if self.3.is_undefined() {
             errors.push("Field 3 is missing".into());
         }
 
         if !errors.is_empty() {
-            panic!("{}", errors.join("\n"));
+            panic!("{}\n{}", "Unable to build StructTuple:", errors.join("\n"));
         } else {
             StructTuple(
                 self.0.unwrap(),
@@ -192,6 +195,7 @@ impl StructTupleBuilder {
 
 // impl_builder / impl_builder_from
 impl ::core::convert::From<StructTupleBuilder> for StructTuple {
+    #[track_caller]
     fn from(builder: StructTupleBuilder) -> Self {
         builder.build()
     }
@@ -66,6 +66,7 @@ impl StructNamedBuilder {
     }
 
     // impl_builder / impl_builder_build
+    #[track_caller]
     pub fn build(self) -> StructNamed {
         let mut built = <StructNamed as ::core::default::Default>::default();
         if self.value.is_set() {
@@ -80,6 +81,7 @@ impl StructNamedBuilder {
 
 // impl_builder / impl_builder_from
 impl ::core::convert::From<StructNamedBuilder> for StructNamed {
+    #[track_caller]
     fn from(builder: StructNamedBuilder) -> Self {
         builder.build()
     }
@@ -133,6 +135,7 @@ impl StructTupleBuilder {
     }
 
     // impl_builder / impl_builder_build
+    #[track_caller]
     pub fn build(self) -> StructTuple {
         let mut built = <StructTuple as ::core::default::Default>::default();
         if self.0.is_set() {
@@ -147,6 +150,7 @@ impl StructTupleBuilder {
 
 // impl_builder / impl_builder_from
 impl ::core::convert::From<StructTupleBuilder> for StructTuple {
+    #[track_caller]
     fn from(builder: StructTupleBuilder) -> Self {
         builder.build()
     }
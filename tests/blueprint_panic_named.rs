@@ -41,8 +41,9 @@ impl FoobarBuilder {
     }
 
     // impl_builder / impl_builder_build
+    #[track_caller]
     pub fn build(self) -> Foobar {
-        let mut errors: ::std::vec::Vec<::std::string::String> = ::std::vec![];
+        let mut errors: ::macon::__private::Vec<::macon::__private::String> = ::macon::__private::vec![];
 
         if self.path1.is_undefined() {
             errors.push("Field path1 is missing".into());
@@ -52,7 +53,7 @@ impl FoobarBuilder {
         }
 
         if !errors.is_empty() {
-            panic!("{}", errors.join("\n"));
+            panic!("{}\n{}", "Unable to build Foobar:", errors.join("\n"));
         } else {
             Foobar {
                 path1: self.path1.unwrap(),
@@ -64,6 +65,7 @@ impl FoobarBuilder {
 
 // impl_builder / impl_builder_from
 impl ::core::convert::From<FoobarBuilder> for Foobar {
+    #[track_caller]
     fn from(builder: FoobarBuilder) -> Self {
         builder.build()
     }
@@ -31,15 +31,16 @@ impl FoobarBuilder {
     }
 
     // impl_builder / impl_builder_build
+    #[track_caller]
     pub fn build(self) -> Foobar {
-        let mut errors: ::std::vec::Vec<::std::string::String> = ::std::vec![];
+        let mut errors: ::macon::__private::Vec<::macon::__private::String> = ::macon::__private::vec![];
 
         if self.f.is_undefined() {
             errors.push("Field f is missing".into());
         }
 
         if !errors.is_empty() {
-            panic!("{}", errors.join("\n"));
+            panic!("{}\n{}", "Unable to build Foobar:", errors.join("\n"));
         } else {
             Foobar {
                 f: self.f.unwrap(),
@@ -50,6 +51,7 @@ impl FoobarBuilder {
 
 // impl_builder / impl_builder_from
 impl ::core::convert::From<FoobarBuilder> for Foobar {
+    #[track_caller]
     fn from(builder: FoobarBuilder) -> Self {
         builder.build()
     }
@@ -56,15 +56,16 @@ impl NamedBuilder {
     }
 
     // impl_builder / impl_builder_build
+    #[track_caller]
     pub fn build(self) -> Named {
-        let mut errors: ::std::vec::Vec<::std::string::String> = ::std::vec![];
+        let mut errors: ::macon::__private::Vec<::macon::__private::String> = ::macon::__private::vec![];
 
         if self.mandatory.is_undefined() {
             errors.push("Field mandatory is missing".into());
         }
 
         if !errors.is_empty() {
-            panic!("{}", errors.join("\n"));
+            panic!("{}\n{}", "Unable to build Named:", errors.join("\n"));
         } else {
             Named {
                 mandatory: self.mandatory.unwrap(),
@@ -76,6 +77,7 @@ impl NamedBuilder {
 
 // impl_builder / impl_builder_from
 impl ::core::convert::From<NamedBuilder> for Named {
+    #[track_caller]
     fn from(builder: NamedBuilder) -> Self {
         builder.build()
     }
@@ -118,15 +120,16 @@ impl TupleBuilder {
     }
 
     // impl_builder / impl_builder_build
+    #[track_caller]
     pub fn build(self) -> Tuple {
-        let mut errors: ::std::vec::Vec<::std::string::String> = ::std::vec![];
+        let mut errors: ::macon::__private::Vec<::macon::__private::String> = ::macon::__private::vec![];
 
         if self.0.is_undefined() {
             errors.push("Field 0 is missing".into());
         }
 
         if !errors.is_empty() {
-            panic!("{}", errors.join("\n"));
+            panic!("{}\n{}", "Unable to build Tuple:", errors.join("\n"));
         } else {
             Tuple(
                 self.0.unwrap(),
@@ -138,6 +141,7 @@ impl TupleBuilder {
 
 // impl_builder / impl_builder_from
 impl ::core::convert::From<TupleBuilder> for Tuple {
+    #[track_caller]
     fn from(builder: TupleBuilder) -> Self {
         builder.build()
     }
@@ -83,8 +83,8 @@ impl StructNamedBuilder {
     }
 
     // impl_builder / impl_builder_build
-    pub fn build(self) -> ::core::result::Result<StructNamed,::std::string::String> {
-        let mut errors: ::std::vec::Vec<::std::string::String> = ::std::vec![];
+    pub fn build(self) -> ::core::result::Result<StructNamed,::macon::__private::String> {
+        let mut errors: ::macon::__private::Vec<::macon::__private::String> = ::macon::__private::vec![];
 
         if self.mandatory.is_undefined() {
             errors.push("Field mandatory is missing".into());
@@ -105,7 +105,7 @@ impl StructNamedBuilder {
 
 // impl_builder / impl_builder_from
 impl ::core::convert::TryFrom<StructNamedBuilder> for StructNamed {
-    type Error = ::std::string::String;
+    type Error = ::macon::__private::String;
     fn try_from(builder: StructNamedBuilder) -> ::core::result::Result<Self, Self::Error> {
         builder.build()
     }
@@ -171,8 +171,8 @@ impl StructTupleBuilder {
     }
 
     // impl_builder / impl_builder_build
-    pub fn build(self) -> ::core::result::Result<StructTuple,::std::string::String> {
-        let mut errors: ::std::vec::Vec<::std::string::String> = ::std::vec![];
+    pub fn build(self) -> ::core::result::Result<StructTuple,::macon::__private::String> {
+        let mut errors: ::macon::__private::Vec<::macon::__private::String> = ::macon::__private::vec![];
 
         if self.3.is_undefined() {
             errors.push("Field 3 is missing".into());
@@ -193,7 +193,7 @@ impl StructTupleBuilder {
 
 // impl_builder / impl_builder_from
 impl ::core::convert::TryFrom<StructTupleBuilder> for StructTuple {
-    type Error = ::std::string::String;
+    type Error = ::macon::__private::String;
     fn try_from(builder: StructTupleBuilder) -> ::core::result::Result<Self, Self::Error> {
         builder.build()
     }
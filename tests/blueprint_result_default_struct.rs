@@ -66,7 +66,7 @@ impl StructNamedBuilder {
     }
 
     // impl_builder / impl_builder_build
-    pub fn build(self) -> ::core::result::Result<StructNamed, ::std::string::String> {
+    pub fn build(self) -> ::core::result::Result<StructNamed, ::macon::__private::String> {
         let mut built = <StructNamed as ::core::default::Default>::default();
         if self.value.is_set() {
             built.value = self.value.unwrap().unwrap();
@@ -80,7 +80,7 @@ impl StructNamedBuilder {
 
 // impl_builder / impl_builder_from
 impl ::core::convert::TryFrom<StructNamedBuilder> for StructNamed {
-    type Error = ::std::string::String;
+    type Error = ::macon::__private::String;
     fn try_from(builder: StructNamedBuilder) -> ::core::result::Result<Self, Self::Error> {
         builder.build()
     }
@@ -134,7 +134,7 @@ impl StructTupleBuilder {
     }
 
     // impl_builder / impl_builder_build
-    pub fn build(self) -> ::core::result::Result<StructTuple, ::std::string::String> {
+    pub fn build(self) -> ::core::result::Result<StructTuple, ::macon::__private::String> {
         let mut built = <StructTuple as ::core::default::Default>::default();
         if self.0.is_set() {
             built.0 = self.0.unwrap().unwrap();
@@ -148,7 +148,7 @@ impl StructTupleBuilder {
 
 // impl_builder / impl_builder_from
 impl ::core::convert::TryFrom<StructTupleBuilder> for StructTuple {
-    type Error = ::std::string::String;
+    type Error = ::macon::__private::String;
     fn try_from(builder: StructTupleBuilder) -> ::core::result::Result<Self, Self::Error> {
         builder.build()
     }
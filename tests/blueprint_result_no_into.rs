@@ -31,8 +31,8 @@ impl FoobarBuilder {
     }
 
     // impl_builder / impl_builder_build
-    pub fn build(self) -> ::core::result::Result<Foobar,::std::string::String> {
-        let mut errors: ::std::vec::Vec<::std::string::String> = ::std::vec![];
+    pub fn build(self) -> ::core::result::Result<Foobar,::macon::__private::String> {
+        let mut errors: ::macon::__private::Vec<::macon::__private::String> = ::macon::__private::vec![];
 
         if self.f.is_undefined() {
             errors.push("Field f is missing".into());
@@ -50,7 +50,7 @@ impl FoobarBuilder {
 
 // impl_builder / impl_builder_from
 impl ::core::convert::TryFrom<FoobarBuilder> for Foobar {
-    type Error = ::std::string::String;
+    type Error = ::macon::__private::String;
     fn try_from(builder: FoobarBuilder) -> Result<Self, Self::Error> {
         builder.build()
     }
@@ -55,8 +55,8 @@ impl NamedBuilder {
     }
 
     // impl_builder / impl_builder_build
-    pub fn build(self) -> ::core::result::Result<Named,::std::string::String> {
-        let mut errors: ::std::vec::Vec<::std::string::String> = ::std::vec![];
+    pub fn build(self) -> ::core::result::Result<Named,::macon::__private::String> {
+        let mut errors: ::macon::__private::Vec<::macon::__private::String> = ::macon::__private::vec![];
 
         if self.mandatory.is_undefined() {
             errors.push("Field mandatory is missing".into());
@@ -75,7 +75,7 @@ impl NamedBuilder {
 
 // impl_builder / impl_builder_from
 impl ::core::convert::TryFrom<NamedBuilder> for Named {
-    type Error = ::std::string::String;
+    type Error = ::macon::__private::String;
 
     fn try_from(builder: NamedBuilder) -> Result<Self, Self::Error> {
         builder.build()
@@ -118,8 +118,8 @@ impl TupleBuilder {
     }
 
     // impl_builder / impl_builder_build
-    pub fn build(self) -> ::core::result::Result<Tuple,::std::string::String> {
-        let mut errors: ::std::vec::Vec<::std::string::String> = ::std::vec![];
+    pub fn build(self) -> ::core::result::Result<Tuple,::macon::__private::String> {
+        let mut errors: ::macon::__private::Vec<::macon::__private::String> = ::macon::__private::vec![];
 
         if self.0.is_undefined() {
             errors.push("Field 0 is missing".into());
@@ -138,7 +138,7 @@ impl TupleBuilder {
 
 // impl_builder / impl_builder_from
 impl ::core::convert::TryFrom<TupleBuilder> for Tuple {
-    type Error = ::std::string::String;
+    type Error = ::macon::__private::String;
 
     fn try_from(builder: TupleBuilder) -> Result<Self, Self::Error> {
         builder.build()
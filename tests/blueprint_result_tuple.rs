@@ -25,6 +25,7 @@ impl Foobar {
 struct FoobarBuilder(
     ::macon::Building<PathBuf>,
     ::macon::Building<PathBuf>,
+    ::macon::__private::Cursor,
 );
 
 // impl_builder
@@ -40,10 +41,16 @@ impl FoobarBuilder {
         self
     }
 
+    // impl_builder / impl_builder_setters_positional
+    pub fn set<V, const FIELD: usize>(mut self, value: V) -> Self where Self: FoobarBuilderPosition<V, FIELD> {
+        <Self as FoobarBuilderPosition<V, FIELD>>::__set_position(&mut self, value);
+        self
+    }
+
     // impl_builder / impl_builder_build
     // impl_builder / impl_builder_build / impl_builder_build_from_scratch
-    pub fn build(self) -> ::core::result::Result<Foobar, ::std::string::String> {
-        let mut errors: ::std::vec::Vec<::std::string::String> = ::std::vec![];
+    pub fn build(self) -> ::core::result::Result<Foobar, ::macon::__private::String> {
+        let mut errors: ::macon::__private::Vec<::macon::__private::String> = ::macon::__private::vec![];
 
         if self.0.is_undefined() {
             errors.push("Field 0 is missing".into());
@@ -51,6 +58,13 @@ impl FoobarBuilder {
         if self.1.is_undefined() {
             errors.push("Field 1 is missing".into());
         }
+        if let ::core::option::Option::Some(position) = self.2.failed() {
+            errors.push(match position {
+                0 => "Field 0 can't be assigned from positional setter",
+                1 => "Field 1 can't be assigned from positional setter",
+                _ => "Too many positional values",
+            }.into());
+        }
 
         if !errors.is_empty() {
             ::core::result::Result::Err(errors.join("\n"))
@@ -65,13 +79,29 @@ impl FoobarBuilder {
 
 // impl_builder / impl_builder_from
 impl ::core::convert::TryFrom<FoobarBuilder> for Foobar {
-    type Error = ::std::string::String;
+    type Error = ::macon::__private::String;
 
     fn try_from(builder: FoobarBuilder) -> Result<Self, Self::Error> {
         builder.build()
     }
 }
 
+// positions
+#[diagnostic::on_unimplemented(message = "no field of `Foobar` has type `{V}`", label = "positional `set()` requires exact field type (no `Into` conversion)")]
+trait FoobarBuilderPosition<V, const FIELD: usize> {
+    fn __set_position(&mut self, value: V);
+}
+
+impl FoobarBuilderPosition<PathBuf, 0> for FoobarBuilder {
+    fn __set_position(&mut self, value: PathBuf) {
+        match self.2.advance() {
+            0 => self.0 = ::macon::Building::Set(value),
+            1 => self.1 = ::macon::Building::Set(value),
+            position => self.2.fail(position),
+        }
+    }
+}
+
 // #############################################################################
 // ################################### TESTS ###################################
 // #############################################################################
@@ -127,3 +157,18 @@ fn builder_into_missing() {
         built,
     );
 }
+
+#[test]
+fn builder_build_positional() {
+    let built = Foobar::builder()
+        .set(PathBuf::from("/tmp/builder_build.0"))
+        .set(PathBuf::from("/tmp/builder_build.1"))
+        .build();
+    assert_eq!(
+        Ok(Foobar(
+            PathBuf::from("/tmp/builder_build.0"),
+            PathBuf::from("/tmp/builder_build.1"),
+        )),
+        built,
+    );
+}
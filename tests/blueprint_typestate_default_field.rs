@@ -31,123 +31,117 @@ impl StructNamed {
 }
 
 // struct_builder
-#[derive(Default,)]
-struct StructNamedBuilder<ID=(),VALUE=(),OPTIONAL=(),MANDATORY=(),> {
+struct StructNamedBuilder<MANDATORY=::macon::Unset,> {
     id: ::macon::Defaulting<i32>,
     value: ::macon::Defaulting<String>,
     optional: ::macon::Defaulting<Option<String>>,
-    mandatory: MANDATORY,
-    __typestate_markers: ::core::marker::PhantomData<(ID,VALUE,OPTIONAL,)>,
+    mandatory: ::macon::Building<PathBuf>,
+    __typestate_markers: ::core::marker::PhantomData<(MANDATORY,)>,
 }
 
 // impl_builder
-// impl_builder / impl_builder_setter
-impl<VALUE,OPTIONAL,MANDATORY,> StructNamedBuilder<(),VALUE,OPTIONAL,MANDATORY,> {
-    pub fn id<ID: ::core::convert::Into<i32>>(self, id: ID) -> StructNamedBuilder<i32,VALUE,OPTIONAL,MANDATORY,> {
-        StructNamedBuilder {
-            id: ::macon::Defaulting::Set(id.into()),
-            value: self.value,
-            optional: self.optional,
-            mandatory: self.mandatory,
-            __typestate_markers: ::core::default::Default::default(),
-        }
-    }
-
-    pub fn id_default(self) -> StructNamedBuilder<i32,VALUE,OPTIONAL,MANDATORY,> {
+// impl_builder / impl_builder_default
+impl ::core::default::Default for StructNamedBuilder {
+    fn default() -> Self {
         StructNamedBuilder {
             id: ::macon::Defaulting::Default,
-            value: self.value,
-            optional: self.optional,
-            mandatory: self.mandatory,
-            __typestate_markers: ::core::default::Default::default(),
+            value: ::macon::Defaulting::Default,
+            optional: ::macon::Defaulting::Default,
+            mandatory: ::macon::Building::Undefined,
+            __typestate_markers: ::core::marker::PhantomData,
         }
     }
 }
-// impl_builder / impl_builder_setter
-impl<ID,OPTIONAL,MANDATORY,> StructNamedBuilder<ID,(),OPTIONAL,MANDATORY,> {
-    pub fn value<VALUE: ::core::convert::Into<String>>(self, value: VALUE) -> StructNamedBuilder<ID,String,OPTIONAL,MANDATORY,> {
-        StructNamedBuilder {
-            id: self.id,
-            value: ::macon::Defaulting::Set(value.into()),
-            optional: self.optional,
-            mandatory: self.mandatory,
-            __typestate_markers: ::core::default::Default::default(),
-        }
+
+// impl_builder / impl_builder_setters
+impl<MANDATORY,> StructNamedBuilder<MANDATORY,> {
+    // impl_builder / impl_builder_setters / impl_builder_setter (untracked)
+    pub fn id<__VALUE: ::core::convert::Into<i32>>(mut self, id: __VALUE) -> Self {
+        self.id = ::macon::Defaulting::Set(id.into());
+        self
     }
 
-    pub fn value_default(self) -> StructNamedBuilder<ID,String,OPTIONAL,MANDATORY,> {
-        StructNamedBuilder {
-            id: self.id,
-            value: ::macon::Defaulting::Default,
-            optional: self.optional,
-            mandatory: self.mandatory,
-            __typestate_markers: ::core::default::Default::default(),
-        }
+    pub fn id_default(mut self) -> Self {
+        self.id = ::macon::Defaulting::Default;
+        self
     }
-}
-// impl_builder / impl_builder_setter
-impl<ID,VALUE,MANDATORY,> StructNamedBuilder<ID,VALUE,(),MANDATORY,> {
-    pub fn optional<OPTIONAL: ::core::convert::Into<String>>(self, optional: OPTIONAL) -> StructNamedBuilder<ID,VALUE,Option<String>,MANDATORY,> {
-        StructNamedBuilder {
-            id: self.id,
-            value: self.value,
-            optional: ::macon::Defaulting::Set(::core::option::Option::Some(optional.into())),
-            mandatory: self.mandatory,
-            __typestate_markers: ::core::default::Default::default(),
-        }
+
+    // impl_builder / impl_builder_setters / impl_builder_setter (untracked)
+    pub fn value<__VALUE: ::core::convert::Into<String>>(mut self, value: __VALUE) -> Self {
+        self.value = ::macon::Defaulting::Set(value.into());
+        self
     }
-    pub fn optional_none(self) -> StructNamedBuilder<ID,VALUE,Option<String>,MANDATORY,> {
-        StructNamedBuilder {
-            id: self.id,
-            value: self.value,
-            optional: ::macon::Defaulting::Set(::core::option::Option::None),
-            mandatory: self.mandatory,
-            __typestate_markers: ::core::default::Default::default(),
-        }
+
+    pub fn value_default(mut self) -> Self {
+        self.value = ::macon::Defaulting::Default;
+        self
     }
-    pub fn optional_default(self) -> StructNamedBuilder<ID,VALUE,Option<String>,MANDATORY,> {
-        StructNamedBuilder {
-            id: self.id,
-            value: self.value,
-            optional: ::macon::Defaulting::Default,
-            mandatory: self.mandatory,
-            __typestate_markers: ::core::default::Default::default(),
-        }
+
+    // impl_builder / impl_builder_setters / impl_builder_setter (untracked)
+    pub fn optional<__VALUE: ::core::convert::Into<String>>(mut self, optional: __VALUE) -> Self {
+        self.optional = ::macon::Defaulting::Set(::core::option::Option::Some(optional.into()));
+        self
+    }
+
+    pub fn optional_none(mut self) -> Self {
+        self.optional = ::macon::Defaulting::Set(::core::option::Option::None);
+        self
+    }
+
+    pub fn optional_default(mut self) -> Self {
+        self.optional = ::macon::Defaulting::Default;
+        self
     }
-}
 
-// impl_builder / impl_builder_setter
-impl<ID,VALUE,OPTIONAL,> StructNamedBuilder<ID,VALUE,OPTIONAL,(),> {
-    pub fn mandatory<MANDATORY: ::core::convert::Into<PathBuf>>(self, mandatory: MANDATORY) -> StructNamedBuilder<ID,VALUE,OPTIONAL,PathBuf,> {
+    // impl_builder / impl_builder_setters / impl_builder_setter
+    pub fn mandatory<__VALUE: ::core::convert::Into<PathBuf>>(mut self, mandatory: __VALUE) -> StructNamedBuilder<::macon::Set,> where MANDATORY: StructNamedBuilderMandatoryUnset, {
+        self.mandatory = ::macon::Building::Set(mandatory.into());
+        self.__typestate()
+    }
+
+    // impl_builder / impl_builder_setters / impl_builder_transition
+    fn __typestate<__MANDATORY,>(self) -> StructNamedBuilder<__MANDATORY,> {
         StructNamedBuilder {
             id: self.id,
             value: self.value,
             optional: self.optional,
-            mandatory: mandatory.into(),
-            __typestate_markers: ::core::default::Default::default(),
+            mandatory: self.mandatory,
+            __typestate_markers: ::core::marker::PhantomData,
         }
     }
 }
 
 // impl_builder / impl_builder_build
-impl<ID,VALUE,OPTIONAL,> StructNamedBuilder<ID,VALUE,OPTIONAL,PathBuf,> {
-    pub fn build(self) -> StructNamed {
+impl<MANDATORY,> StructNamedBuilder<MANDATORY,> {
+    pub fn build(self) -> StructNamed where Self: StructNamedBuilderHasMandatory, {
         StructNamed {
             id: self.id.unwrap(),
             value: self.value.unwrap(),
             optional: self.optional.unwrap(),
-            mandatory: self.mandatory,
+            mandatory: self.mandatory.unwrap(),
         }
     }
 }
 
 // impl_builder / impl_builder_from
-impl<ID,VALUE,OPTIONAL,> ::core::convert::From<StructNamedBuilder<ID,VALUE,OPTIONAL,PathBuf,>> for StructNamed {
-    fn from(builder: StructNamedBuilder<ID,VALUE,OPTIONAL,PathBuf,>) -> Self {
+impl<> ::core::convert::From<StructNamedBuilder<::macon::Set,>> for StructNamed {
+    fn from(builder: StructNamedBuilder<::macon::Set,>) -> Self {
         builder.build()
     }
 }
 
+// field_markers (extract)
+#[diagnostic::on_unimplemented(message = "field `mandatory` was already set", label = "`mandatory` can only be set once")]
+trait StructNamedBuilderMandatoryUnset {}
+
+impl StructNamedBuilderMandatoryUnset for ::macon::Unset {}
+
+// field_traits (extract)
+#[diagnostic::on_unimplemented(message = "field `mandatory` of `StructNamed` must be set before calling build()", label = "`mandatory` is not set")]
+trait StructNamedBuilderHasMandatory {}
+
+impl<> StructNamedBuilderHasMandatory for StructNamedBuilder<::macon::Set,> {}
+
 // impl_target
 impl StructTuple {
     pub fn builder() -> StructTupleBuilder {
@@ -156,159 +150,172 @@ impl StructTuple {
 }
 
 // struct_builder
-#[derive(Default,)]
-struct StructTupleBuilder<V0=(),V1=(),V2=(),V3=(),>(
+struct StructTupleBuilder<V0=::macon::Unset,V1=::macon::Unset,V2=::macon::Unset,V3=::macon::Unset,>(
     ::macon::Defaulting<i32>,
     ::macon::Defaulting<String>,
     ::macon::Defaulting<Option<String>>,
-    V3,
-    ::core::marker::PhantomData<(V0,V1,V2,)>,
+    ::macon::Building<PathBuf>,
+    ::core::marker::PhantomData<(V0,V1,V2,V3,)>,
 );
 
 // impl_builder
-// impl_builder / impl_builder_setter
-impl<V1,V2,V3,> StructTupleBuilder<(),V1,V2,V3,> {
-    pub fn set0<V0: ::core::convert::Into<i32>>(self, v0: V0) -> StructTupleBuilder<i32,V1,V2,V3,> {
-        StructTupleBuilder(
-            ::macon::Defaulting::Set(v0.into()),
-            self.1,
-            self.2,
-            self.3,
-            ::core::default::Default::default(),
-        )
-    }
-    pub fn set0_default(self) -> StructTupleBuilder<i32,V1,V2,V3,> {
+// impl_builder / impl_builder_default
+impl ::core::default::Default for StructTupleBuilder {
+    fn default() -> Self {
         StructTupleBuilder(
             ::macon::Defaulting::Default,
-            self.1,
-            self.2,
-            self.3,
-            ::core::default::Default::default(),
+            ::macon::Defaulting::Default,
+            ::macon::Defaulting::Default,
+            ::macon::Building::Undefined,
+            ::core::marker::PhantomData,
         )
     }
 }
 
-impl StructTupleBuilder<(),(),(),()> {
-    pub fn set<V0: ::core::convert::Into<i32>>(self, v0: V0) -> StructTupleBuilder<i32,(),(),()> {
-        self.set0(v0)
+// impl_builder / impl_builder_setters
+impl<V0,V1,V2,V3,> StructTupleBuilder<V0,V1,V2,V3,> {
+    // impl_builder / impl_builder_setters / impl_builder_setter
+    pub fn set0<__VALUE: ::core::convert::Into<i32>>(mut self, v0: __VALUE) -> StructTupleBuilder<::macon::Set,V1,V2,V3,> where V0: StructTupleBuilderV0Unset, {
+        self.0 = ::macon::Defaulting::Set(v0.into());
+        self.__typestate()
     }
-    pub fn default(self) -> StructTupleBuilder<i32,(),(),()> {
-        self.set0_default()
+
+    pub fn set0_default(mut self) -> StructTupleBuilder<::macon::Set,V1,V2,V3,> where V0: StructTupleBuilderV0Unset, {
+        self.0 = ::macon::Defaulting::Default;
+        self.__typestate()
     }
-}
 
-// impl_builder / impl_builder_setter
-impl<V0,V2,V3,> StructTupleBuilder<V0,(),V2,V3,> {
-    pub fn set1<V1: ::core::convert::Into<String>>(self, v1: V1) -> StructTupleBuilder<V0,String,V2,V3,> {
-        StructTupleBuilder(
-            self.0,
-            ::macon::Defaulting::Set(v1.into()),
-            self.2,
-            self.3,
-            ::core::default::Default::default(),
-        )
+    // impl_builder / impl_builder_setters / impl_builder_setter
+    pub fn set1<__VALUE: ::core::convert::Into<String>>(mut self, v1: __VALUE) -> StructTupleBuilder<V0,::macon::Set,V2,V3,> where V1: StructTupleBuilderV1Unset, {
+        self.1 = ::macon::Defaulting::Set(v1.into());
+        self.__typestate()
+    }
+
+    pub fn set1_default(mut self) -> StructTupleBuilder<V0,::macon::Set,V2,V3,> where V1: StructTupleBuilderV1Unset, {
+        self.1 = ::macon::Defaulting::Default;
+        self.__typestate()
+    }
+
+    // impl_builder / impl_builder_setters / impl_builder_setter
+    pub fn set2<__VALUE: ::core::convert::Into<String>>(mut self, v2: __VALUE) -> StructTupleBuilder<V0,V1,::macon::Set,V3,> where V2: StructTupleBuilderV2Unset, {
+        self.2 = ::macon::Defaulting::Set(::core::option::Option::Some(v2.into()));
+        self.__typestate()
+    }
+
+    pub fn set2_none(mut self) -> StructTupleBuilder<V0,V1,::macon::Set,V3,> where V2: StructTupleBuilderV2Unset, {
+        self.2 = ::macon::Defaulting::Set(::core::option::Option::None);
+        self.__typestate()
     }
-    pub fn set1_default(self) -> StructTupleBuilder<V0,String,V2,V3,> {
+
+    pub fn set2_default(mut self) -> StructTupleBuilder<V0,V1,::macon::Set,V3,> where V2: StructTupleBuilderV2Unset, {
+        self.2 = ::macon::Defaulting::Default;
+        self.__typestate()
+    }
+
+    // impl_builder / impl_builder_setters / impl_builder_setter
+    pub fn set3<__VALUE: ::core::convert::Into<PathBuf>>(mut self, v3: __VALUE) -> StructTupleBuilder<V0,V1,V2,::macon::Set,> where V3: StructTupleBuilderV3Unset, {
+        self.3 = ::macon::Building::Set(v3.into());
+        self.__typestate()
+    }
+
+    // impl_builder / impl_builder_setters / impl_builder_transition
+    fn __typestate<__V0,__V1,__V2,__V3,>(self) -> StructTupleBuilder<__V0,__V1,__V2,__V3,> {
         StructTupleBuilder(
             self.0,
-            ::macon::Defaulting::Default,
+            self.1,
             self.2,
             self.3,
-            ::core::default::Default::default(),
+            ::core::marker::PhantomData,
         )
     }
 }
 
-impl StructTupleBuilder<i32,(),(),(),> {
-    pub fn set<V1: ::core::convert::Into<String>>(self, v1: V1) -> StructTupleBuilder<i32,String,(),(),> {
-        self.set1(v1)
+// impl_builder / impl_builder_setters / impl_builder_setter_ordered
+impl StructTupleBuilder<::macon::Unset,::macon::Unset,::macon::Unset,::macon::Unset,> {
+    pub fn set<__VALUE: ::core::convert::Into<i32>>(self, v0: __VALUE) -> StructTupleBuilder<::macon::Set,::macon::Unset,::macon::Unset,::macon::Unset,> {
+        self.set0(v0)
     }
-    pub fn default(self) -> StructTupleBuilder<i32,String,(),(),> {
-        self.set1_default()
+    pub fn default(self) -> StructTupleBuilder<::macon::Set,::macon::Unset,::macon::Unset,::macon::Unset,> {
+        self.set0_default()
     }
 }
 
-// impl_builder / impl_builder_setter
-impl<V0,V1,V3,> StructTupleBuilder<V0,V1,(),V3,> {
-    pub fn set2<V2: ::core::convert::Into<String>>(self, v2: V2) -> StructTupleBuilder<V0,V1,Option<String>,V3,> {
-        StructTupleBuilder(
-            self.0,
-            self.1,
-            ::macon::Defaulting::Set(::core::option::Option::Some(v2.into())),
-            self.3,
-            ::core::default::Default::default(),
-        )
-    }
-    pub fn set2_none(self) -> StructTupleBuilder<V0,V1,Option<String>,V3,> {
-        StructTupleBuilder(
-            self.0,
-            self.1,
-            ::macon::Defaulting::Set(::core::option::Option::None),
-            self.3,
-            ::core::default::Default::default(),
-        )
+// impl_builder / impl_builder_setters / impl_builder_setter_ordered
+impl StructTupleBuilder<::macon::Set,::macon::Unset,::macon::Unset,::macon::Unset,> {
+    pub fn set<__VALUE: ::core::convert::Into<String>>(self, v1: __VALUE) -> StructTupleBuilder<::macon::Set,::macon::Set,::macon::Unset,::macon::Unset,> {
+        self.set1(v1)
     }
-    pub fn set2_default(self) -> StructTupleBuilder<V0,V1,Option<String>,V3,> {
-        StructTupleBuilder(
-            self.0,
-            self.1,
-            ::macon::Defaulting::Default,
-            self.3,
-            ::core::default::Default::default(),
-        )
+    pub fn default(self) -> StructTupleBuilder<::macon::Set,::macon::Set,::macon::Unset,::macon::Unset,> {
+        self.set1_default()
     }
 }
 
-impl StructTupleBuilder<i32,String,(),(),> {
-    pub fn set<V2: ::core::convert::Into<String>>(self, v2: V2) -> StructTupleBuilder<i32,String,Option<String>,(),> {
+// impl_builder / impl_builder_setters / impl_builder_setter_ordered
+impl StructTupleBuilder<::macon::Set,::macon::Set,::macon::Unset,::macon::Unset,> {
+    pub fn set<__VALUE: ::core::convert::Into<String>>(self, v2: __VALUE) -> StructTupleBuilder<::macon::Set,::macon::Set,::macon::Set,::macon::Unset,> {
         self.set2(v2)
     }
-    pub fn none(self) -> StructTupleBuilder<i32,String,Option<String>,(),> {
+    pub fn none(self) -> StructTupleBuilder<::macon::Set,::macon::Set,::macon::Set,::macon::Unset,> {
         self.set2_none()
     }
-    pub fn default(self) -> StructTupleBuilder<i32,String,Option<String>,(),> {
+    pub fn default(self) -> StructTupleBuilder<::macon::Set,::macon::Set,::macon::Set,::macon::Unset,> {
         self.set2_default()
     }
 }
 
-// impl_builder / impl_builder_setter
-impl<V0,V1,V2,> StructTupleBuilder<V0,V1,V2,(),> {
-    pub fn set3<V3: ::core::convert::Into<PathBuf>>(self, v3: V3) -> StructTupleBuilder<V0,V1,V2,PathBuf,> {
-        StructTupleBuilder(
-            self.0,
-            self.1,
-            self.2,
-            v3.into(),
-            ::core::default::Default::default(),
-        )
-    }
-}
-
-impl StructTupleBuilder<i32,String,Option<String>,(),> {
-    pub fn set<V3: ::core::convert::Into<PathBuf>>(self, v3: V3) -> StructTupleBuilder<i32,String,Option<String>,PathBuf,> {
+// impl_builder / impl_builder_setters / impl_builder_setter_ordered
+impl StructTupleBuilder<::macon::Set,::macon::Set,::macon::Set,::macon::Unset,> {
+    pub fn set<__VALUE: ::core::convert::Into<PathBuf>>(self, v3: __VALUE) -> StructTupleBuilder<::macon::Set,::macon::Set,::macon::Set,::macon::Set,> {
         self.set3(v3)
     }
 }
 
 // impl_builder / impl_builder_build
-impl<V0,V1,V2,> StructTupleBuilder<V0,V1,V2,PathBuf,> {
-    pub fn build(self) -> StructTuple {
+impl<V0,V1,V2,V3,> StructTupleBuilder<V0,V1,V2,V3,> {
+    pub fn build(self) -> StructTuple where Self: StructTupleBuilderHasV3, {
         StructTuple(
             self.0.unwrap(),
             self.1.unwrap(),
             self.2.unwrap(),
-            self.3,
+            self.3.unwrap(),
         )
     }
 }
 
 // impl_builder / impl_builder_from
-impl<V0,V1,V2,> ::core::convert::From<StructTupleBuilder<V0,V1,V2,PathBuf,>> for StructTuple {
-    fn from(builder: StructTupleBuilder<V0,V1,V2,PathBuf,>) -> Self {
+impl<V0,V1,V2,> ::core::convert::From<StructTupleBuilder<V0,V1,V2,::macon::Set,>> for StructTuple {
+    fn from(builder: StructTupleBuilder<V0,V1,V2,::macon::Set,>) -> Self {
         builder.build()
     }
 }
 
+// field_markers (extract)
+#[diagnostic::on_unimplemented(message = "field `0` was already set", label = "`0` can only be set once")]
+trait StructTupleBuilderV0Unset {}
+
+impl StructTupleBuilderV0Unset for ::macon::Unset {}
+
+#[diagnostic::on_unimplemented(message = "field `1` was already set", label = "`1` can only be set once")]
+trait StructTupleBuilderV1Unset {}
+
+impl StructTupleBuilderV1Unset for ::macon::Unset {}
+
+#[diagnostic::on_unimplemented(message = "field `2` was already set", label = "`2` can only be set once")]
+trait StructTupleBuilderV2Unset {}
+
+impl StructTupleBuilderV2Unset for ::macon::Unset {}
+
+#[diagnostic::on_unimplemented(message = "field `3` was already set", label = "`3` can only be set once")]
+trait StructTupleBuilderV3Unset {}
+
+impl StructTupleBuilderV3Unset for ::macon::Unset {}
+
+// field_traits (extract)
+#[diagnostic::on_unimplemented(message = "field `3` of `StructTuple` must be set before calling build()", label = "`3` is not set")]
+trait StructTupleBuilderHasV3 {}
+
+impl<V0,V1,V2,> StructTupleBuilderHasV3 for StructTupleBuilder<V0,V1,V2,::macon::Set,> {}
+
 // #############################################################################
 // ################################### TESTS ###################################
 // #############################################################################
@@ -21,78 +21,71 @@ struct StructTuple(
 // impl_target
 impl StructNamed {
     pub fn builder() -> StructNamedBuilder {
-        ::core::default::Default::default()
+        <StructNamedBuilder as ::core::default::Default>::default()
     }
 }
 
 // struct_builder
-#[derive(Default,)]
-struct StructNamedBuilder<VALUE=(),OPTIONAL=(),> {
+struct StructNamedBuilder<> {
     value: ::macon::Keeping<::macon::Defaulting<String>>,
     optional: ::macon::Keeping<::macon::Defaulting<Option<String>>>,
-    __typestate_markers: ::core::marker::PhantomData<(VALUE,OPTIONAL,)>,
+    __typestate_markers: ::core::marker::PhantomData<()>,
 }
 
 // impl_builder
-// impl_builder / impl_builder_setter
-impl<OPTIONAL,> StructNamedBuilder<(),OPTIONAL,> {
-    pub fn value<VALUE: ::core::convert::Into<String>>(self, value: VALUE) -> StructNamedBuilder<String,OPTIONAL,> {
-        StructNamedBuilder {
-            value: ::macon::Keeping::Set(::macon::Defaulting::Set(value.into())),
-            optional: self.optional,
-            __typestate_markers: ::core::default::Default::default(),
-        }
-    }
-    pub fn value_keep(self) -> StructNamedBuilder<String,OPTIONAL,> {
+// impl_builder / impl_builder_default
+impl ::core::default::Default for StructNamedBuilder {
+    fn default() -> Self {
         StructNamedBuilder {
             value: ::macon::Keeping::Keep,
-            optional: self.optional,
-            __typestate_markers: ::core::default::Default::default(),
-        }
-    }
-    pub fn value_default(self) -> StructNamedBuilder<String,OPTIONAL,> {
-        StructNamedBuilder {
-            value: ::macon::Keeping::Set(::macon::Defaulting::Default),
-            optional: self.optional,
-            __typestate_markers: ::core::default::Default::default(),
+            optional: ::macon::Keeping::Keep,
+            __typestate_markers: ::core::marker::PhantomData,
         }
     }
 }
 
-// impl_builder / impl_builder_setter
-impl<VALUE,> StructNamedBuilder<VALUE,(),> {
-    pub fn optional<OPTIONAL: ::core::convert::Into<String>>(self, optional: OPTIONAL) -> StructNamedBuilder<VALUE,Option<String>,> {
-        StructNamedBuilder {
-            value: self.value,
-            optional: ::macon::Keeping::Set(::macon::Defaulting::Set(::core::option::Option::Some(optional.into()))),
-            __typestate_markers: ::core::default::Default::default(),
-        }
+// impl_builder / impl_builder_setters
+impl<> StructNamedBuilder<> {
+    // impl_builder / impl_builder_setters / impl_builder_setter (untracked)
+    pub fn value<__VALUE: ::core::convert::Into<String>>(mut self, value: __VALUE) -> Self {
+        self.value = ::macon::Keeping::Set(::macon::Defaulting::Set(value.into()));
+        self
     }
-    pub fn optional_none(self) -> StructNamedBuilder<VALUE,Option<String>,> {
-        StructNamedBuilder {
-            value: self.value,
-            optional: ::macon::Keeping::Set(::macon::Defaulting::Set(::core::option::Option::None)),
-            __typestate_markers: ::core::default::Default::default(),
-        }
+
+    pub fn value_keep(mut self) -> Self {
+        self.value = ::macon::Keeping::Keep;
+        self
     }
-    pub fn optional_keep(self) -> StructNamedBuilder<VALUE,Option<String>,> {
-        StructNamedBuilder {
-            value: self.value,
-            optional: ::macon::Keeping::Keep,
-            __typestate_markers: ::core::default::Default::default(),
-        }
+
+    pub fn value_default(mut self) -> Self {
+        self.value = ::macon::Keeping::Set(::macon::Defaulting::Default);
+        self
     }
-    pub fn optional_default(self) -> StructNamedBuilder<VALUE,Option<String>,> {
-        StructNamedBuilder {
-            value: self.value,
-            optional: ::macon::Keeping::Set(::macon::Defaulting::Default),
-            __typestate_markers: ::core::default::Default::default(),
-        }
+
+    // impl_builder / impl_builder_setters / impl_builder_setter (untracked)
+    pub fn optional<__VALUE: ::core::convert::Into<String>>(mut self, optional: __VALUE) -> Self {
+        self.optional = ::macon::Keeping::Set(::macon::Defaulting::Set(::core::option::Option::Some(optional.into())));
+        self
+    }
+
+    pub fn optional_none(mut self) -> Self {
+        self.optional = ::macon::Keeping::Set(::macon::Defaulting::Set(::core::option::Option::None));
+        self
+    }
+
+    pub fn optional_keep(mut self) -> Self {
+        self.optional = ::macon::Keeping::Keep;
+        self
+    }
+
+    pub fn optional_default(mut self) -> Self {
+        self.optional = ::macon::Keeping::Set(::macon::Defaulting::Default);
+        self
     }
 }
 
 // impl_builder / impl_builder_build
-impl<VALUE,OPTIONAL,> StructNamedBuilder<VALUE,OPTIONAL,> {
+impl<> StructNamedBuilder<> {
     pub fn build(self) -> StructNamed {
         let mut built = <StructNamed as ::core::default::Default>::default();
         if self.value.is_set() {
@@ -106,8 +99,8 @@ impl<VALUE,OPTIONAL,> StructNamedBuilder<VALUE,OPTIONAL,> {
 }
 
 // impl_builder / impl_builder_from
-impl<VALUE,OPTIONAL,> ::core::convert::From<StructNamedBuilder<VALUE,OPTIONAL,>> for StructNamed {
-    fn from(builder: StructNamedBuilder<VALUE,OPTIONAL,>) -> Self {
+impl<> ::core::convert::From<StructNamedBuilder<>> for StructNamed {
+    fn from(builder: StructNamedBuilder<>) -> Self {
         builder.build()
     }
 }
@@ -115,99 +108,103 @@ impl<VALUE,OPTIONAL,> ::core::convert::From<StructNamedBuilder<VALUE,OPTIONAL,>>
 // impl_target
 impl StructTuple {
     pub fn builder() -> StructTupleBuilder {
-        ::core::default::Default::default()
+        <StructTupleBuilder as ::core::default::Default>::default()
     }
 }
 
 // struct_builder
-#[derive(Default,)]
-struct StructTupleBuilder<V0=(),V1=(),>(
+struct StructTupleBuilder<V0=::macon::Unset,V1=::macon::Unset,>(
     ::macon::Keeping<::macon::Defaulting<String>>,
     ::macon::Keeping<::macon::Defaulting<Option<String>>>,
     ::core::marker::PhantomData<(V0,V1,)>,
 );
 
 // impl_builder
-// impl_builder / impl_builder_setter
-impl<V1,> StructTupleBuilder<(),V1,> {
-    pub fn set0<V0: ::core::convert::Into<String>>(self, v0: V0) -> StructTupleBuilder<String,V1,> {
-        StructTupleBuilder(
-            ::macon::Keeping::Set(::macon::Defaulting::Set(v0.into())),
-            self.1,
-            ::core::default::Default::default(),
-        )
-    }
-    pub fn set0_keep(self) -> StructTupleBuilder<String,V1,> {
+// impl_builder / impl_builder_default
+impl ::core::default::Default for StructTupleBuilder {
+    fn default() -> Self {
         StructTupleBuilder(
             ::macon::Keeping::Keep,
-            self.1,
-            ::core::default::Default::default(),
-        )
-    }
-    pub fn set0_default(self) -> StructTupleBuilder<String,V1,> {
-        StructTupleBuilder(
-            ::macon::Keeping::Set(::macon::Defaulting::Default),
-            self.1,
-            ::core::default::Default::default(),
+            ::macon::Keeping::Keep,
+            ::core::marker::PhantomData,
         )
     }
 }
 
-impl StructTupleBuilder<(),(),> {
-    pub fn set<V0: ::core::convert::Into<String>>(self, v0: V0) -> StructTupleBuilder<String,(),> {
-        self.set0(v0)
+// impl_builder / impl_builder_setters
+impl<V0,V1,> StructTupleBuilder<V0,V1,> {
+    // impl_builder / impl_builder_setters / impl_builder_setter
+    pub fn set0<__VALUE: ::core::convert::Into<String>>(mut self, v0: __VALUE) -> StructTupleBuilder<::macon::Set,V1,> where V0: StructTupleBuilderV0Unset, {
+        self.0 = ::macon::Keeping::Set(::macon::Defaulting::Set(v0.into()));
+        self.__typestate()
     }
-    pub fn keep(self) -> StructTupleBuilder<String,(),> {
-        self.set0_keep()
+
+    pub fn set0_keep(mut self) -> StructTupleBuilder<::macon::Set,V1,> where V0: StructTupleBuilderV0Unset, {
+        self.0 = ::macon::Keeping::Keep;
+        self.__typestate()
     }
-    pub fn default(self) -> StructTupleBuilder<String,(),> {
-        self.set0_default()
+
+    pub fn set0_default(mut self) -> StructTupleBuilder<::macon::Set,V1,> where V0: StructTupleBuilderV0Unset, {
+        self.0 = ::macon::Keeping::Set(::macon::Defaulting::Default);
+        self.__typestate()
     }
-}
 
-// impl_builder / impl_builder_setter
-impl<V0,> StructTupleBuilder<V0,(),> {
-    pub fn set1<V1: ::core::convert::Into<String>>(self, v1: V1) -> StructTupleBuilder<V0,Option<String>,> {
-        StructTupleBuilder(
-            self.0,
-            ::macon::Keeping::Set(::macon::Defaulting::Set(::core::option::Option::Some(v1.into()))),
-            ::core::default::Default::default(),
-        )
+    // impl_builder / impl_builder_setters / impl_builder_setter
+    pub fn set1<__VALUE: ::core::convert::Into<String>>(mut self, v1: __VALUE) -> StructTupleBuilder<V0,::macon::Set,> where V1: StructTupleBuilderV1Unset, {
+        self.1 = ::macon::Keeping::Set(::macon::Defaulting::Set(::core::option::Option::Some(v1.into())));
+        self.__typestate()
     }
-    pub fn set1_none(self) -> StructTupleBuilder<V0,Option<String>,> {
-        StructTupleBuilder(
-            self.0,
-            ::macon::Keeping::Set(::macon::Defaulting::Set(::core::option::Option::None)),
-            ::core::default::Default::default(),
-        )
+
+    pub fn set1_none(mut self) -> StructTupleBuilder<V0,::macon::Set,> where V1: StructTupleBuilderV1Unset, {
+        self.1 = ::macon::Keeping::Set(::macon::Defaulting::Set(::core::option::Option::None));
+        self.__typestate()
     }
-    pub fn set1_keep(self) -> StructTupleBuilder<V0,Option<String>,> {
-        StructTupleBuilder(
-            self.0,
-            ::macon::Keeping::Keep,
-            ::core::default::Default::default(),
-        )
+
+    pub fn set1_keep(mut self) -> StructTupleBuilder<V0,::macon::Set,> where V1: StructTupleBuilderV1Unset, {
+        self.1 = ::macon::Keeping::Keep;
+        self.__typestate()
+    }
+
+    pub fn set1_default(mut self) -> StructTupleBuilder<V0,::macon::Set,> where V1: StructTupleBuilderV1Unset, {
+        self.1 = ::macon::Keeping::Set(::macon::Defaulting::Default);
+        self.__typestate()
     }
-    pub fn set1_default(self) -> StructTupleBuilder<V0,Option<String>,> {
+
+    // impl_builder / impl_builder_setters / impl_builder_transition
+    fn __typestate<__V0,__V1,>(self) -> StructTupleBuilder<__V0,__V1,> {
         StructTupleBuilder(
             self.0,
-            ::macon::Keeping::Set(::macon::Defaulting::Default),
-            ::core::default::Default::default(),
+            self.1,
+            ::core::marker::PhantomData,
         )
     }
 }
 
-impl StructTupleBuilder<String,(),> {
-    pub fn set<V1: ::core::convert::Into<String>>(self, v1: V1) -> StructTupleBuilder<String,Option<String>,> {
+// impl_builder / impl_builder_setters / impl_builder_setter_ordered
+impl StructTupleBuilder<::macon::Unset,::macon::Unset,> {
+    pub fn set<__VALUE: ::core::convert::Into<String>>(self, v0: __VALUE) -> StructTupleBuilder<::macon::Set,::macon::Unset,> {
+        self.set0(v0)
+    }
+    pub fn keep(self) -> StructTupleBuilder<::macon::Set,::macon::Unset,> {
+        self.set0_keep()
+    }
+    pub fn default(self) -> StructTupleBuilder<::macon::Set,::macon::Unset,> {
+        self.set0_default()
+    }
+}
+
+// impl_builder / impl_builder_setters / impl_builder_setter_ordered
+impl StructTupleBuilder<::macon::Set,::macon::Unset,> {
+    pub fn set<__VALUE: ::core::convert::Into<String>>(self, v1: __VALUE) -> StructTupleBuilder<::macon::Set,::macon::Set,> {
         self.set1(v1)
     }
-    pub fn none(self) -> StructTupleBuilder<String,Option<String>,> {
+    pub fn none(self) -> StructTupleBuilder<::macon::Set,::macon::Set,> {
         self.set1_none()
     }
-    pub fn keep(self) -> StructTupleBuilder<String,Option<String>,> {
+    pub fn keep(self) -> StructTupleBuilder<::macon::Set,::macon::Set,> {
         self.set1_keep()
     }
-    pub fn default(self) -> StructTupleBuilder<String,Option<String>,> {
+    pub fn default(self) -> StructTupleBuilder<::macon::Set,::macon::Set,> {
         self.set1_default()
     }
 }
@@ -233,6 +230,17 @@ impl<V0,V1,> ::core::convert::From<StructTupleBuilder<V0,V1,>> for StructTuple {
     }
 }
 
+// field_markers (extract)
+#[diagnostic::on_unimplemented(message = "field `0` was already set", label = "`0` can only be set once")]
+trait StructTupleBuilderV0Unset {}
+
+impl StructTupleBuilderV0Unset for ::macon::Unset {}
+
+#[diagnostic::on_unimplemented(message = "field `1` was already set", label = "`1` can only be set once")]
+trait StructTupleBuilderV1Unset {}
+
+impl StructTupleBuilderV1Unset for ::macon::Unset {}
+
 // #############################################################################
 // ################################### TESTS ###################################
 // #############################################################################
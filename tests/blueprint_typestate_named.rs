@@ -21,52 +21,87 @@ impl Foobar {
 }
 
 // struct_builder
-#[derive(Default,)]
-struct FoobarBuilder<PATH1=(),PATH2=()> {
-    path1: PATH1,
-    path2: PATH2,
-    __typestate_markers: ::core::marker::PhantomData<()>,
+struct FoobarBuilder<PATH1=::macon::Unset,PATH2=::macon::Unset,> {
+    path1: ::macon::Building<PathBuf>,
+    path2: ::macon::Building<PathBuf>,
+    __typestate_markers: ::core::marker::PhantomData<(PATH1,PATH2,)>,
 }
 
 // impl_builder
-// impl_builder / impl_builder_setter
-impl<PATH2> FoobarBuilder<(),PATH2,> {
-    pub fn path1<PATH1: ::core::convert::Into<PathBuf>>(self, path1: PATH1) -> FoobarBuilder<PathBuf,PATH2,> {
+// impl_builder / impl_builder_default
+impl ::core::default::Default for FoobarBuilder {
+    fn default() -> Self {
         FoobarBuilder {
-            path1: path1.into(),
-            path2: self.path2,
-            __typestate_markers: ::core::default::Default::default(),
+            path1: ::macon::Building::Undefined,
+            path2: ::macon::Building::Undefined,
+            __typestate_markers: ::core::marker::PhantomData,
         }
     }
 }
-// impl_builder / impl_builder_setter
-impl<PATH1> FoobarBuilder<PATH1,(),> {
-    pub fn path2<PATH2: ::core::convert::Into<PathBuf>>(self, path2: PATH2) -> FoobarBuilder<PATH1,PathBuf,> {
+
+// impl_builder / impl_builder_setters
+impl<PATH1,PATH2,> FoobarBuilder<PATH1,PATH2,> {
+    // impl_builder / impl_builder_setters / impl_builder_setter
+    pub fn path1<__VALUE: ::core::convert::Into<PathBuf>>(mut self, path1: __VALUE) -> FoobarBuilder<::macon::Set,PATH2,> where PATH1: FoobarBuilderPath1Unset, {
+        self.path1 = ::macon::Building::Set(path1.into());
+        self.__typestate()
+    }
+
+    // impl_builder / impl_builder_setters / impl_builder_setter
+    pub fn path2<__VALUE: ::core::convert::Into<PathBuf>>(mut self, path2: __VALUE) -> FoobarBuilder<PATH1,::macon::Set,> where PATH2: FoobarBuilderPath2Unset, {
+        self.path2 = ::macon::Building::Set(path2.into());
+        self.__typestate()
+    }
+
+    // impl_builder / impl_builder_setters / impl_builder_transition
+    fn __typestate<__PATH1,__PATH2,>(self) -> FoobarBuilder<__PATH1,__PATH2,> {
         FoobarBuilder {
             path1: self.path1,
-            path2: path2.into(),
-            __typestate_markers: ::core::default::Default::default(),
+            path2: self.path2,
+            __typestate_markers: ::core::marker::PhantomData,
         }
     }
 }
 
 // impl_builder / impl_builder_build
-impl<> FoobarBuilder<PathBuf,PathBuf,> {
-    pub fn build(self) -> Foobar {
+impl<PATH1,PATH2,> FoobarBuilder<PATH1,PATH2,> {
+    pub fn build(self) -> Foobar where Self: FoobarBuilderHasPath1, Self: FoobarBuilderHasPath2, {
         Foobar {
-            path1: self.path1,
-            path2: self.path2,
+            path1: self.path1.unwrap(),
+            path2: self.path2.unwrap(),
         }
     }
 }
 
 // impl_builder / impl_builder_from
-impl<> ::core::convert::From<FoobarBuilder<PathBuf,PathBuf,>> for Foobar {
-    fn from(builder: FoobarBuilder<PathBuf,PathBuf,>) -> Self {
+impl<> ::core::convert::From<FoobarBuilder<::macon::Set,::macon::Set,>> for Foobar {
+    fn from(builder: FoobarBuilder<::macon::Set,::macon::Set,>) -> Self {
         builder.build()
     }
 }
 
+// field_markers (extract)
+#[diagnostic::on_unimplemented(message = "field `path1` was already set", label = "`path1` can only be set once")]
+trait FoobarBuilderPath1Unset {}
+
+impl FoobarBuilderPath1Unset for ::macon::Unset {}
+
+#[diagnostic::on_unimplemented(message = "field `path2` was already set", label = "`path2` can only be set once")]
+trait FoobarBuilderPath2Unset {}
+
+impl FoobarBuilderPath2Unset for ::macon::Unset {}
+
+// field_traits (extract)
+#[diagnostic::on_unimplemented(message = "field `path1` of `Foobar` must be set before calling build()", label = "`path1` is not set")]
+trait FoobarBuilderHasPath1 {}
+
+impl<PATH2,> FoobarBuilderHasPath1 for FoobarBuilder<::macon::Set,PATH2,> {}
+
+#[diagnostic::on_unimplemented(message = "field `path2` of `Foobar` must be set before calling build()", label = "`path2` is not set")]
+trait FoobarBuilderHasPath2 {}
+
+impl<PATH1,> FoobarBuilderHasPath2 for FoobarBuilder<PATH1,::macon::Set,> {}
+
 // #############################################################################
 // ################################### TESTS ###################################
 // #############################################################################
@@ -17,39 +17,67 @@ impl Foobar {
 }
 
 // struct_builder
-#[derive(Default,)]
-struct FoobarBuilder<F=(),> {
-    f: F,
-    __typestate_markers: ::core::marker::PhantomData<(Box<dyn Fn(usize) -> usize>,)>,
+struct FoobarBuilder<F=::macon::Unset,> {
+    f: ::macon::Building<Box<dyn Fn(usize) -> usize>>,
+    __typestate_markers: ::core::marker::PhantomData<(F,)>,
 }
 
 // impl_builder
-// impl_builder / impl_builder_setter
-impl<> FoobarBuilder<(),> {
-    pub fn f<>(self, f: Box<dyn Fn(usize) -> usize>) -> FoobarBuilder<Box<dyn Fn(usize) -> usize>,> {
+// impl_builder / impl_builder_default
+impl ::core::default::Default for FoobarBuilder {
+    fn default() -> Self {
         FoobarBuilder {
-            f: f.into(),
-            __typestate_markers: ::core::default::Default::default(),
+            f: ::macon::Building::Undefined,
+            __typestate_markers: ::core::marker::PhantomData,
+        }
+    }
+}
+
+// impl_builder / impl_builder_setters
+impl<F,> FoobarBuilder<F,> {
+    // impl_builder / impl_builder_setters / impl_builder_setter
+    pub fn f(mut self, f: Box<dyn Fn(usize) -> usize>) -> FoobarBuilder<::macon::Set,> where F: FoobarBuilderFUnset, {
+        self.f = ::macon::Building::Set(f);
+        self.__typestate()
+    }
+
+    // impl_builder / impl_builder_setters / impl_builder_transition
+    fn __typestate<__F,>(self) -> FoobarBuilder<__F,> {
+        FoobarBuilder {
+            f: self.f,
+            __typestate_markers: ::core::marker::PhantomData,
         }
     }
 }
 
 // impl_builder / impl_builder_build
-impl<> FoobarBuilder<Box<dyn Fn(usize) -> usize>,> {
-    pub fn build(self) -> Foobar {
+impl<F,> FoobarBuilder<F,> {
+    pub fn build(self) -> Foobar where Self: FoobarBuilderHasF, {
         Foobar {
-            f: self.f,
+            f: self.f.unwrap(),
         }
     }
 }
 
 // impl_builder / impl_builder_from
-impl<> ::core::convert::From<FoobarBuilder<Box<dyn Fn(usize) -> usize>,>> for Foobar {
-    fn from(builder: FoobarBuilder<Box<dyn Fn(usize) -> usize>,>) -> Self {
+impl<> ::core::convert::From<FoobarBuilder<::macon::Set,>> for Foobar {
+    fn from(builder: FoobarBuilder<::macon::Set,>) -> Self {
         builder.build()
     }
 }
 
+// field_markers (extract)
+#[diagnostic::on_unimplemented(message = "field `f` was already set", label = "`f` can only be set once")]
+trait FoobarBuilderFUnset {}
+
+impl FoobarBuilderFUnset for ::macon::Unset {}
+
+// field_traits (extract)
+#[diagnostic::on_unimplemented(message = "field `f` of `Foobar` must be set before calling build()", label = "`f` is not set")]
+trait FoobarBuilderHasF {}
+
+impl<> FoobarBuilderHasF for FoobarBuilder<::macon::Set,> {}
+
 // #############################################################################
 // ################################### TESTS ###################################
 // #############################################################################
@@ -27,70 +27,87 @@ impl Named {
 }
 
 // struct_builder
-#[derive(Default,)]
-struct NamedBuilder<MANDATORY=(),OPTION=(),> {
-    mandatory: MANDATORY,
+struct NamedBuilder<MANDATORY=::macon::Unset,> {
+    mandatory: ::macon::Building<PathBuf>,
     option: Option<PathBuf>,
-    __typestate_markers: ::core::marker::PhantomData<(OPTION,)>,
+    __typestate_markers: ::core::marker::PhantomData<(MANDATORY,)>,
 }
 
 // impl_builder
-// impl_builder / impl_builder_setter
-impl<OPTION,> NamedBuilder<(),OPTION,> {
-
-    pub fn mandatory<MANDATORY: ::core::convert::Into<PathBuf>>(self, mandatory: MANDATORY) -> NamedBuilder<PathBuf,OPTION,> {
+// impl_builder / impl_builder_default
+impl ::core::default::Default for NamedBuilder {
+    fn default() -> Self {
         NamedBuilder {
-            mandatory: mandatory.into(),
-            option: self.option,
-            __typestate_markers: ::core::default::Default::default(),
+            mandatory: ::macon::Building::Undefined,
+            option: ::core::option::Option::None,
+            __typestate_markers: ::core::marker::PhantomData,
         }
     }
 }
-// impl_builder / impl_builder_setter
-impl<MANDATORY,> NamedBuilder<MANDATORY,(),> {
 
-    pub fn option_optional<OPTION: ::core::convert::Into<PathBuf>>(self, option: ::core::option::Option<OPTION>) -> NamedBuilder<MANDATORY,Option<PathBuf>,> {
-        NamedBuilder {
-            mandatory: self.mandatory,
-            option: option.map(::core::convert::Into::into),
-            __typestate_markers: ::core::default::Default::default(),
-        }
+// impl_builder / impl_builder_setters
+impl<MANDATORY,> NamedBuilder<MANDATORY,> {
+    // impl_builder / impl_builder_setters / impl_builder_setter
+    pub fn mandatory<__VALUE: ::core::convert::Into<PathBuf>>(mut self, mandatory: __VALUE) -> NamedBuilder<::macon::Set,> where MANDATORY: NamedBuilderMandatoryUnset, {
+        self.mandatory = ::macon::Building::Set(mandatory.into());
+        self.__typestate()
     }
 
-    pub fn option<OPTION: ::core::convert::Into<PathBuf>>(self, option: OPTION) -> NamedBuilder<MANDATORY,Option<PathBuf>,> {
-        NamedBuilder {
-            mandatory: self.mandatory,
-            option: ::core::option::Option::Some(option.into()),
-            __typestate_markers: ::core::default::Default::default(),
-        }
+    // impl_builder / impl_builder_setters / impl_builder_setter (untracked)
+    pub fn option<__VALUE: ::core::convert::Into<PathBuf>>(mut self, option: __VALUE) -> Self {
+        self.option = ::core::option::Option::Some(option.into());
+        self
+    }
+
+    pub fn option_none(mut self) -> Self {
+        self.option = ::core::option::Option::None;
+        self
+    }
+
+    pub fn option_optional<__VALUE: ::core::convert::Into<PathBuf>>(mut self, option: ::core::option::Option<__VALUE>) -> Self {
+        self.option = option.map(::core::convert::Into::into);
+        self
     }
 
-    pub fn option_none(self) -> NamedBuilder<MANDATORY,Option<PathBuf>,> {
+    // impl_builder / impl_builder_setters / impl_builder_transition
+    fn __typestate<__MANDATORY,>(self) -> NamedBuilder<__MANDATORY,> {
         NamedBuilder {
             mandatory: self.mandatory,
-            option: ::core::option::Option::None,
-            __typestate_markers: ::core::default::Default::default(),
+            option: self.option,
+            __typestate_markers: ::core::marker::PhantomData,
         }
     }
 }
 
 // impl_builder / impl_builder_build
-impl<OPTION,> NamedBuilder<PathBuf,OPTION,> {
-    pub fn build(self) -> Named {
+impl<MANDATORY,> NamedBuilder<MANDATORY,> {
+    pub fn build(self) -> Named where Self: NamedBuilderHasMandatory, {
         Named {
-            mandatory: self.mandatory,
+            mandatory: self.mandatory.unwrap(),
             option: self.option,
         }
     }
 }
 
 // impl_builder / impl_builder_from
-impl<OPTION,> ::core::convert::From<NamedBuilder<PathBuf,OPTION,>> for Named {
-    fn from(builder: NamedBuilder<PathBuf,OPTION,>) -> Self {
+impl ::core::convert::From<NamedBuilder<::macon::Set,>> for Named {
+    fn from(builder: NamedBuilder<::macon::Set,>) -> Self {
         builder.build()
     }
 }
 
+// field_markers (extract)
+#[diagnostic::on_unimplemented(message = "field `mandatory` was already set", label = "`mandatory` can only be set once")]
+trait NamedBuilderMandatoryUnset {}
+
+impl NamedBuilderMandatoryUnset for ::macon::Unset {}
+
+// field_traits (extract)
+#[diagnostic::on_unimplemented(message = "field `mandatory` of `Named` must be set before calling build()", label = "`mandatory` is not set")]
+trait NamedBuilderHasMandatory {}
+
+impl NamedBuilderHasMandatory for NamedBuilder<::macon::Set,> {}
+
 // impl_target
 impl Tuple {
     pub fn builder() -> TupleBuilder {
@@ -99,85 +116,112 @@ impl Tuple {
 }
 
 // struct_builder
-#[derive(Default,)]
-struct TupleBuilder<V0=(),V1=(),>(
-    V0,
+struct TupleBuilder<V0=::macon::Unset,V1=::macon::Unset,>(
+    ::macon::Building<PathBuf>,
     Option<PathBuf>,
-    ::core::marker::PhantomData<(V1,)>,
+    ::core::marker::PhantomData<(V0,V1,)>,
 );
 
 // impl_builder
-// impl_builder / impl_builder_setter
-impl<V1,> TupleBuilder<(),V1,> {
-    pub fn set0<V0: ::core::convert::Into<PathBuf>>(self, v0: V0) -> TupleBuilder<PathBuf,V1,> {
+// impl_builder / impl_builder_default
+impl ::core::default::Default for TupleBuilder {
+    fn default() -> Self {
         TupleBuilder(
-            v0.into(),
-            self.1,
-            ::core::default::Default::default(),
+            ::macon::Building::Undefined,
+            ::core::option::Option::None,
+            ::core::marker::PhantomData,
         )
     }
 }
-impl TupleBuilder<(),()> {
-    pub fn set<V0: ::core::convert::Into<PathBuf>>(self, v0: V0) -> TupleBuilder<PathBuf,(),> {
-        self.set0(v0)
+
+// impl_builder / impl_builder_setters
+impl<V0,V1,> TupleBuilder<V0,V1,> {
+    // impl_builder / impl_builder_setters / impl_builder_setter
+    pub fn set0<__VALUE: ::core::convert::Into<PathBuf>>(mut self, v0: __VALUE) -> TupleBuilder<::macon::Set,V1,> where V0: TupleBuilderV0Unset, {
+        self.0 = ::macon::Building::Set(v0.into());
+        self.__typestate()
     }
-}
-// impl_builder / impl_builder_setter
-impl<V0,> TupleBuilder<V0,(),> {
 
-    pub fn set1_optional<V1: ::core::convert::Into<PathBuf>>(self, v1: ::core::option::Option<V1>) -> TupleBuilder<V0,Option<PathBuf>,> {
-        TupleBuilder(
-            self.0,
-            v1.map(::core::convert::Into::into),
-            ::core::default::Default::default(),
-        )
+    // impl_builder / impl_builder_setters / impl_builder_setter
+    pub fn set1<__VALUE: ::core::convert::Into<PathBuf>>(mut self, v1: __VALUE) -> TupleBuilder<V0,::macon::Set,> where V1: TupleBuilderV1Unset, {
+        self.1 = ::core::option::Option::Some(v1.into());
+        self.__typestate()
     }
 
-    pub fn set1<V1: ::core::convert::Into<PathBuf>>(self, v1: V1) -> TupleBuilder<V0,Option<PathBuf>,> {
-        TupleBuilder(
-            self.0,
-            ::core::option::Option::Some(v1.into()),
-            ::core::default::Default::default(),
-        )
+    pub fn set1_none(mut self) -> TupleBuilder<V0,::macon::Set,> where V1: TupleBuilderV1Unset, {
+        self.1 = ::core::option::Option::None;
+        self.__typestate()
     }
 
-    pub fn set1_none(self) -> TupleBuilder<V0,Option<PathBuf>,> {
+    pub fn set1_optional<__VALUE: ::core::convert::Into<PathBuf>>(mut self, v1: ::core::option::Option<__VALUE>) -> TupleBuilder<V0,::macon::Set,> where V1: TupleBuilderV1Unset, {
+        self.1 = v1.map(::core::convert::Into::into);
+        self.__typestate()
+    }
+
+    // impl_builder / impl_builder_setters / impl_builder_transition
+    fn __typestate<__V0,__V1,>(self) -> TupleBuilder<__V0,__V1,> {
         TupleBuilder(
             self.0,
-            ::core::option::Option::None,
-            ::core::default::Default::default(),
+            self.1,
+            ::core::marker::PhantomData,
         )
     }
 }
-impl TupleBuilder<PathBuf,()> {
-    pub fn optional<V1: ::core::convert::Into<PathBuf>>(self, v1: ::core::option::Option<V1>) -> TupleBuilder<PathBuf,Option<PathBuf>,> {
-        self.set1_optional(v1)
+
+// impl_builder / impl_builder_setters / impl_builder_setter_ordered
+impl TupleBuilder<::macon::Unset,::macon::Unset,> {
+    pub fn set<__VALUE: ::core::convert::Into<PathBuf>>(self, v0: __VALUE) -> TupleBuilder<::macon::Set,::macon::Unset,> {
+        self.set0(v0)
     }
-    pub fn set<V1: ::core::convert::Into<PathBuf>>(self, v1: V1) -> TupleBuilder<PathBuf,Option<PathBuf>,> {
+}
+
+// impl_builder / impl_builder_setters / impl_builder_setter_ordered
+impl TupleBuilder<::macon::Set,::macon::Unset,> {
+    pub fn set<__VALUE: ::core::convert::Into<PathBuf>>(self, v1: __VALUE) -> TupleBuilder<::macon::Set,::macon::Set,> {
         self.set1(v1)
     }
-    pub fn none(self) -> TupleBuilder<PathBuf,Option<PathBuf>,> {
+    pub fn none(self) -> TupleBuilder<::macon::Set,::macon::Set,> {
         self.set1_none()
     }
+    pub fn optional<__VALUE: ::core::convert::Into<PathBuf>>(self, v1: ::core::option::Option<__VALUE>) -> TupleBuilder<::macon::Set,::macon::Set,> {
+        self.set1_optional(v1)
+    }
 }
 
 // impl_builder / impl_builder_build
-impl<V1,> TupleBuilder<PathBuf,V1,> {
-    pub fn build(self) -> Tuple {
+impl<V0,V1,> TupleBuilder<V0,V1,> {
+    pub fn build(self) -> Tuple where Self: TupleBuilderHasV0, {
         Tuple(
-            self.0,
+            self.0.unwrap(),
             self.1,
         )
     }
 }
 
 // impl_builder / impl_builder_from
-impl<V1,> ::core::convert::From<TupleBuilder<PathBuf,V1,>> for Tuple {
-    fn from(builder: TupleBuilder<PathBuf,V1,>) -> Self {
+impl<V1,> ::core::convert::From<TupleBuilder<::macon::Set,V1,>> for Tuple {
+    fn from(builder: TupleBuilder<::macon::Set,V1,>) -> Self {
         builder.build()
     }
 }
 
+// field_markers (extract)
+#[diagnostic::on_unimplemented(message = "field `0` was already set", label = "`0` can only be set once")]
+trait TupleBuilderV0Unset {}
+
+impl TupleBuilderV0Unset for ::macon::Unset {}
+
+#[diagnostic::on_unimplemented(message = "field `1` was already set", label = "`1` can only be set once")]
+trait TupleBuilderV1Unset {}
+
+impl TupleBuilderV1Unset for ::macon::Unset {}
+
+// field_traits (extract)
+#[diagnostic::on_unimplemented(message = "field `0` of `Tuple` must be set before calling build()", label = "`0` is not set")]
+trait TupleBuilderHasV0 {}
+
+impl<V1,> TupleBuilderHasV0 for TupleBuilder<::macon::Set,V1,> {}
+
 // #############################################################################
 // ################################### TESTS ###################################
 // #############################################################################
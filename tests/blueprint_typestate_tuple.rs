@@ -21,65 +21,101 @@ impl Foobar {
 }
 
 // struct_builder
-#[derive(Default,)]
-struct FoobarBuilder<V0=(),V1=(),>(
-    V0,
-    V1,
-    ::core::marker::PhantomData<()>,
+struct FoobarBuilder<V0=::macon::Unset,V1=::macon::Unset,>(
+    ::macon::Building<PathBuf>,
+    ::macon::Building<PathBuf>,
+    ::core::marker::PhantomData<(V0,V1,)>,
 );
 
 // impl_builder
-// impl_builder / impl_builder_setter
-impl<V1,> FoobarBuilder<(),V1,> {
-    pub fn set0<V0: ::core::convert::Into<PathBuf>>(self, v0: V0) -> FoobarBuilder<PathBuf,V1,> {
+// impl_builder / impl_builder_default
+impl ::core::default::Default for FoobarBuilder {
+    fn default() -> Self {
         FoobarBuilder(
-            v0.into(),
-            self.1,
-            ::core::default::Default::default(),
+            ::macon::Building::Undefined,
+            ::macon::Building::Undefined,
+            ::core::marker::PhantomData,
         )
     }
 }
 
-impl FoobarBuilder<(),(),> {
-    pub fn set<V0: ::core::convert::Into<PathBuf>>(self, v0: V0) -> FoobarBuilder<PathBuf,(),> {
-        self.set0(v0)
+// impl_builder / impl_builder_setters
+impl<V0,V1,> FoobarBuilder<V0,V1,> {
+    // impl_builder / impl_builder_setters / impl_builder_setter
+    pub fn set0<__VALUE: ::core::convert::Into<PathBuf>>(mut self, v0: __VALUE) -> FoobarBuilder<::macon::Set,V1,> where V0: FoobarBuilderV0Unset, {
+        self.0 = ::macon::Building::Set(v0.into());
+        self.__typestate()
+    }
+
+    // impl_builder / impl_builder_setters / impl_builder_setter
+    pub fn set1<__VALUE: ::core::convert::Into<PathBuf>>(mut self, v1: __VALUE) -> FoobarBuilder<V0,::macon::Set,> where V1: FoobarBuilderV1Unset, {
+        self.1 = ::macon::Building::Set(v1.into());
+        self.__typestate()
     }
-}
 
-// impl_builder / impl_builder_setter
-impl<V0,> FoobarBuilder<V0,(),> {
-    pub fn set1<V1: ::core::convert::Into<PathBuf>>(self, v1: V1) -> FoobarBuilder<V0,PathBuf,> {
+    // impl_builder / impl_builder_setters / impl_builder_transition
+    fn __typestate<__V0,__V1,>(self) -> FoobarBuilder<__V0,__V1,> {
         FoobarBuilder(
             self.0,
-            v1.into(),
-            ::core::default::Default::default(),
+            self.1,
+            ::core::marker::PhantomData,
         )
     }
 }
 
-impl FoobarBuilder<PathBuf,(),> {
-    pub fn set<V1: ::core::convert::Into<PathBuf>>(self, v1: V1) -> FoobarBuilder<PathBuf,PathBuf,> {
+// impl_builder / impl_builder_setters / impl_builder_setter_ordered
+impl FoobarBuilder<::macon::Unset,::macon::Unset,> {
+    pub fn set<__VALUE: ::core::convert::Into<PathBuf>>(self, v0: __VALUE) -> FoobarBuilder<::macon::Set,::macon::Unset,> {
+        self.set0(v0)
+    }
+}
+
+// impl_builder / impl_builder_setters / impl_builder_setter_ordered
+impl FoobarBuilder<::macon::Set,::macon::Unset,> {
+    pub fn set<__VALUE: ::core::convert::Into<PathBuf>>(self, v1: __VALUE) -> FoobarBuilder<::macon::Set,::macon::Set,> {
         self.set1(v1)
     }
 }
 
 // impl_builder / impl_builder_build
-impl<> FoobarBuilder<PathBuf,PathBuf,> {
-    pub fn build(self) -> Foobar {
+impl<V0,V1,> FoobarBuilder<V0,V1,> {
+    pub fn build(self) -> Foobar where Self: FoobarBuilderHasV0, Self: FoobarBuilderHasV1, {
         Foobar(
-            self.0,
-            self.1,
+            self.0.unwrap(),
+            self.1.unwrap(),
         )
     }
 }
 
 // impl_builder / impl_builder_from
-impl<> ::core::convert::From<FoobarBuilder<PathBuf,PathBuf,>> for Foobar {
-    fn from(builder: FoobarBuilder<PathBuf,PathBuf,>) -> Self {
+impl<> ::core::convert::From<FoobarBuilder<::macon::Set,::macon::Set,>> for Foobar {
+    fn from(builder: FoobarBuilder<::macon::Set,::macon::Set,>) -> Self {
         builder.build()
     }
 }
 
+// field_markers (extract)
+#[diagnostic::on_unimplemented(message = "field `0` was already set", label = "`0` can only be set once")]
+trait FoobarBuilderV0Unset {}
+
+impl FoobarBuilderV0Unset for ::macon::Unset {}
+
+#[diagnostic::on_unimplemented(message = "field `1` was already set", label = "`1` can only be set once")]
+trait FoobarBuilderV1Unset {}
+
+impl FoobarBuilderV1Unset for ::macon::Unset {}
+
+// field_traits (extract)
+#[diagnostic::on_unimplemented(message = "field `0` of `Foobar` must be set before calling build()", label = "`0` is not set")]
+trait FoobarBuilderHasV0 {}
+
+impl<V1,> FoobarBuilderHasV0 for FoobarBuilder<::macon::Set,V1,> {}
+
+#[diagnostic::on_unimplemented(message = "field `1` of `Foobar` must be set before calling build()", label = "`1` is not set")]
+trait FoobarBuilderHasV1 {}
+
+impl<V0,> FoobarBuilderHasV1 for FoobarBuilder<V0,::macon::Set,> {}
+
 // #############################################################################
 // ################################### TESTS ###################################
 // #############################################################################
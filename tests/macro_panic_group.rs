@@ -0,0 +1,135 @@
+use macon::Builder;
+
+// #############################################################################
+// ################################### INPUT ###################################
+// #############################################################################
+#[derive(Builder)]
+#[builder(mode=Panic)]
+#[derive(PartialEq,Debug)]
+struct Connection {
+    host: String,
+    #[builder(group(auth, exactly_one))]
+    password: Option<String>,
+    #[builder(group(auth))]
+    token: Option<String>,
+    #[builder(group(proxy, at_most_one))]
+    http_proxy: Option<String>,
+    #[builder(group(proxy))]
+    socks_proxy: Option<String>,
+    #[builder(group(contact, at_least_one))]
+    email: Option<String>,
+    #[builder(group(contact))]
+    phone: Option<String>,
+}
+
+#[derive(Builder)]
+#[builder(mode=Panic)]
+#[derive(Default,PartialEq,Debug)]
+struct DefaultConnection {
+    #[builder(group(auth, exactly_one))]
+    password: Option<String>,
+    #[builder(group(auth))]
+    token: Option<String>,
+}
+
+// #############################################################################
+// ################################### TESTS ###################################
+// #############################################################################
+
+#[test]
+fn builder_build_full() {
+    let built = Connection::builder()
+        .host("localhost")
+        .token("secret")
+        .http_proxy("http://proxy")
+        .email("admin@localhost")
+        .phone("0123456789")
+        .build();
+    assert_eq!(
+        Connection {
+            host: String::from("localhost"),
+            password: None,
+            token: Some(String::from("secret")),
+            http_proxy: Some(String::from("http://proxy")),
+            socks_proxy: None,
+            email: Some(String::from("admin@localhost")),
+            phone: Some(String::from("0123456789")),
+        },
+        built,
+    );
+}
+
+#[test]
+fn builder_build_optional_none() {
+    let built = Connection::builder()
+        .host("localhost")
+        .password_none()
+        .token_optional(Some("secret"))
+        .email("admin@localhost")
+        .build();
+    assert_eq!(
+        Connection {
+            host: String::from("localhost"),
+            password: None,
+            token: Some(String::from("secret")),
+            http_proxy: None,
+            socks_proxy: None,
+            email: Some(String::from("admin@localhost")),
+            phone: None,
+        },
+        built,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Group auth requires exactly one of password, token to be set")]
+fn builder_build_exactly_one_missing() {
+    Connection::builder()
+        .host("localhost")
+        .email("admin@localhost")
+        .build();
+}
+
+#[test]
+#[should_panic(expected = "Group auth requires exactly one of password, token to be set")]
+fn builder_build_exactly_one_many() {
+    Connection::builder()
+        .host("localhost")
+        .password("secret")
+        .token("secret")
+        .email("admin@localhost")
+        .build();
+}
+
+#[test]
+#[should_panic(expected = "Group proxy requires at most one of http_proxy, socks_proxy to be set")]
+fn builder_build_at_most_one_many() {
+    Connection::builder()
+        .host("localhost")
+        .password("secret")
+        .email("admin@localhost")
+        .http_proxy("http://proxy")
+        .socks_proxy("socks5://proxy")
+        .build();
+}
+
+#[test]
+fn builder_build_default_struct() {
+    let built = DefaultConnection::builder()
+        .token("secret")
+        .build();
+    assert_eq!(
+        DefaultConnection {
+            password: None,
+            token: Some(String::from("secret")),
+        },
+        built,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Group auth requires exactly one of password, token to be set")]
+fn builder_build_default_struct_missing() {
+    DefaultConnection::builder()
+        .build();
+}
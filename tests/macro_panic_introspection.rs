@@ -0,0 +1,106 @@
+use macon::Builder;
+
+// #############################################################################
+// ################################### INPUT ###################################
+// #############################################################################
+use std::path::PathBuf;
+
+#[derive(Builder)]
+#[builder(mode=Panic)]
+#[derive(PartialEq,Debug)]
+struct Foobar {
+    path: PathBuf,
+    port: u16,
+    name: Option<String>,
+    #[builder(Default=!)]
+    alias: Option<String>,
+}
+
+#[derive(Builder)]
+#[builder(mode=Panic)]
+#[derive(Default,PartialEq,Debug)]
+struct WithDefault {
+    port: u16,
+    name: Option<String>,
+}
+
+#[derive(Builder)]
+#[builder(mode=Panic)]
+#[derive(PartialEq,Debug)]
+struct Tuple(
+    PathBuf,
+    Option<String>,
+);
+
+// #############################################################################
+// ################################### TESTS ###################################
+// #############################################################################
+
+#[test]
+fn builder_getters_empty() {
+    let builder = Foobar::builder();
+    assert_eq!(None, builder.get_path());
+    assert_eq!(None, builder.get_port());
+    assert_eq!(None, builder.get_name());
+    assert_eq!(None, builder.get_alias());
+    assert!(!builder.is_path_set(), "is_path_set");
+    assert!(!builder.is_port_set(), "is_port_set");
+    assert!(!builder.is_name_set(), "is_name_set");
+    assert!(!builder.is_alias_set(), "is_alias_set");
+}
+
+#[test]
+fn builder_getters_full() {
+    let builder = Foobar::builder()
+        .path("/tmp/introspection")
+        .port(8080u16)
+        .name("foobar")
+        .alias("foo");
+    assert_eq!(Some(&PathBuf::from("/tmp/introspection")), builder.get_path());
+    assert_eq!(Some(&8080), builder.get_port());
+    assert_eq!(Some(&Some(String::from("foobar"))), builder.get_name());
+    assert_eq!(Some(&Some(String::from("foo"))), builder.get_alias());
+    assert!(builder.is_path_set(), "is_path_set");
+    assert!(builder.is_port_set(), "is_port_set");
+    assert!(builder.is_name_set(), "is_name_set");
+    assert!(builder.is_alias_set(), "is_alias_set");
+}
+
+#[test]
+fn builder_getters_default_struct() {
+    let builder = WithDefault::builder()
+        .port(8080u16)
+        .name_keep();
+    assert_eq!(Some(&8080), builder.get_port());
+    assert_eq!(None, builder.get_name());
+    assert!(builder.is_port_set(), "is_port_set");
+    assert!(!builder.is_name_set(), "is_name_set");
+    assert_eq!(Vec::<&str>::new(), builder.missing_fields());
+}
+
+#[test]
+fn builder_missing_fields() {
+    assert_eq!(
+        vec!["path"],
+        Foobar::builder()
+            .port(8080u16)
+            .missing_fields(),
+    );
+    assert_eq!(
+        Vec::<&str>::new(),
+        Foobar::builder()
+            .path("/tmp/introspection")
+            .missing_fields(),
+    );
+}
+
+#[test]
+fn builder_tuple() {
+    let builder = Tuple::builder()
+        .set1("foobar");
+    assert_eq!(None, builder.get0());
+    assert_eq!(Some(&Some(String::from("foobar"))), builder.get1());
+    assert!(!builder.is_set0(), "is_set0");
+    assert!(builder.is_set1(), "is_set1");
+    assert_eq!(vec!["0"], builder.missing_fields());
+}
@@ -0,0 +1,121 @@
+use macon::Builder;
+
+// #############################################################################
+// ################################### INPUT ###################################
+// #############################################################################
+use std::path::PathBuf;
+
+#[derive(Builder)]
+#[builder(mode=Panic)]
+#[derive(PartialEq,Debug)]
+struct Foobar {
+    path: PathBuf,
+    port: u16,
+    name: Option<String>,
+}
+
+#[derive(Builder)]
+#[builder(mode=Panic)]
+#[derive(Default,PartialEq,Debug)]
+struct WithDefault {
+    port: u16,
+    name: Option<String>,
+}
+
+#[derive(Builder)]
+#[builder(mode=Panic)]
+#[derive(PartialEq,Debug)]
+struct Unit;
+
+// #############################################################################
+// ################################### TESTS ###################################
+// #############################################################################
+
+#[test]
+fn builder_merge() {
+    let built = Foobar::builder()
+        .path("/tmp/self")
+        .port(8080u16)
+        .name("self")
+        .merge(
+            Foobar::builder()
+                .port(9090u16)
+                .name_none()
+        )
+        .build();
+    assert_eq!(
+        Foobar {
+            path: PathBuf::from("/tmp/self"),
+            port: 9090,
+            name: None,
+        },
+        built,
+    );
+}
+
+#[test]
+fn builder_merge_default() {
+    let built = Foobar::builder()
+        .path("/tmp/self")
+        .port(8080u16)
+        .merge(
+            Foobar::builder()
+                .path("/tmp/other")
+                .port_default()
+        )
+        .build();
+    assert_eq!(
+        Foobar {
+            path: PathBuf::from("/tmp/other"),
+            port: 8080,
+            name: None,
+        },
+        built,
+    );
+}
+
+#[test]
+fn builder_or() {
+    let built = Foobar::builder()
+        .port(8080u16)
+        .or(
+            Foobar::builder()
+                .path("/tmp/other")
+                .port(9090u16)
+                .name("other")
+        )
+        .build();
+    assert_eq!(
+        Foobar {
+            path: PathBuf::from("/tmp/other"),
+            port: 8080,
+            name: Some(String::from("other")),
+        },
+        built,
+    );
+}
+
+#[test]
+fn builder_merge_keep() {
+    let built = WithDefault::builder()
+        .port(8080u16)
+        .name("self")
+        .merge(
+            WithDefault::builder()
+                .port_keep()
+                .name_default()
+        )
+        .build();
+    assert_eq!(
+        WithDefault {
+            port: 8080,
+            name: Some(String::from("self")),
+        },
+        built,
+    );
+}
+
+#[test]
+fn builder_merge_unit() {
+    assert_eq!(Unit, Unit::builder().merge(Unit::builder()).build());
+}
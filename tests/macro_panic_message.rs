@@ -0,0 +1,38 @@
+use macon::Builder;
+use std::path::PathBuf;
+
+// #############################################################################
+// ################################### INPUT ###################################
+// #############################################################################
+#[derive(Builder)]
+#[builder(mode=Panic)]
+#[derive(PartialEq,Debug)]
+struct Server {
+    host: PathBuf,
+    path: PathBuf,
+}
+
+#[derive(Builder)]
+#[builder(mode=Panic, panic_message = "Invalid client configuration:")]
+#[derive(PartialEq,Debug)]
+struct Client {
+    host: PathBuf,
+}
+
+// #############################################################################
+// ################################### TESTS ###################################
+// #############################################################################
+
+#[test]
+#[should_panic(expected = "Unable to build Server:\nField host is missing\nField path is missing")]
+fn builder_panic_message_default() {
+    Server::builder()
+        .build();
+}
+
+#[test]
+#[should_panic(expected = "Invalid client configuration:\nField host is missing")]
+fn builder_panic_message_custom() {
+    Client::builder()
+        .build();
+}
@@ -0,0 +1,48 @@
+use macon::Builder;
+use std::panic::{
+    self,
+    Location,
+};
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+// #############################################################################
+// ################################### INPUT ###################################
+// #############################################################################
+#[derive(Builder)]
+#[builder(mode=Panic)]
+#[derive(PartialEq,Debug)]
+struct Server {
+    host: PathBuf,
+}
+
+static LOCATION: Mutex<Option<(String, u32)>> = Mutex::new(None);
+
+/// Run `f` and return location of its panic
+fn panic_location<F: FnOnce() + panic::UnwindSafe>(f: F) -> (String, u32) {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|info| {
+        *LOCATION.lock().unwrap() = info.location().map(|l| (l.file().to_owned(), l.line()));
+    }));
+    let result = panic::catch_unwind(f);
+    panic::set_hook(hook);
+    assert!(result.is_err(), "should panic");
+    LOCATION.lock().unwrap().take().expect("panic location")
+}
+
+// #############################################################################
+// ################################### TESTS ###################################
+// #############################################################################
+
+// Single test: panic hook is process-wide
+#[test]
+fn builder_track_caller() {
+    let (file, line) = panic_location(|| { Server::builder().build(); });
+    assert_eq!((Location::caller().file().to_owned(), line!() - 1), (file, line), "build()");
+
+    let (file, line) = panic_location(|| { let _: Server = Server::builder().into(); });
+    assert_eq!((Location::caller().file().to_owned(), line!() - 1), (file, line), "From::from()");
+
+    let (file, line) = panic_location(|| { macon::Builder::build(Server::builder()); });
+    assert_eq!((Location::caller().file().to_owned(), line!() - 1), (file, line), "Builder::build()");
+}
@@ -0,0 +1,73 @@
+use macon::{
+    Buildable,
+    Builder,
+};
+
+// #############################################################################
+// ################################### INPUT ###################################
+// #############################################################################
+use std::path::PathBuf;
+
+#[derive(Builder)]
+#[builder(mode=Panic)]
+#[derive(PartialEq,Debug)]
+struct Foobar {
+    path: PathBuf,
+    port: u16,
+}
+
+#[derive(Builder)]
+#[builder(mode=Panic)]
+#[derive(Default,PartialEq,Debug)]
+struct WithDefault {
+    port: u16,
+}
+
+// #############################################################################
+// ################################### TESTS ###################################
+// #############################################################################
+
+fn fixture<T: Buildable>() -> T::Builder {
+    T::builder()
+}
+
+fn finish<B: Builder>(builder: B) -> B::Output {
+    builder.build()
+}
+
+#[test]
+fn buildable() {
+    let built = fixture::<Foobar>()
+        .path("/tmp/traits")
+        .port(8080u16)
+        .build();
+    assert_eq!(
+        Foobar {
+            path: PathBuf::from("/tmp/traits"),
+            port: 8080,
+        },
+        built,
+    );
+}
+
+#[test]
+fn builder() {
+    let builder = Foobar::builder()
+        .path("/tmp/traits")
+        .port(8080u16);
+    assert_eq!(
+        Foobar {
+            path: PathBuf::from("/tmp/traits"),
+            port: 8080,
+        },
+        finish(builder),
+    );
+}
+
+#[test]
+fn buildable_builder() {
+    assert_eq!(
+        WithDefault::default(),
+        finish(fixture::<WithDefault>()),
+    );
+}
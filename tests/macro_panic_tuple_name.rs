@@ -0,0 +1,78 @@
+use macon::Builder;
+
+// #############################################################################
+// ################################### INPUT ###################################
+// #############################################################################
+#[derive(Builder)]
+#[builder(mode=Panic, fields(Default=!))]
+#[derive(PartialEq,Debug)]
+struct Rgb(
+    #[builder(name = red, Into=!)]
+    u8,
+    #[builder(name = green, Into=!)]
+    u8,
+    #[builder(name = blue, Into=!)]
+    u8,
+);
+
+#[derive(Builder)]
+#[builder(mode=Panic)]
+#[derive(PartialEq,Debug)]
+struct Labeled(
+    #[builder(name = label)]
+    String,
+    Option<String>,
+);
+
+// #############################################################################
+// ################################### TESTS ###################################
+// #############################################################################
+
+#[test]
+fn builder_tuple_name() {
+    let built = Rgb::builder()
+        .blue(0)
+        .red(255)
+        .green(128)
+        .build();
+    assert_eq!(
+        Rgb(255, 128, 0),
+        built,
+    );
+}
+
+#[test]
+fn builder_tuple_name_mixed() {
+    let built = Labeled::builder()
+        .label("foo")
+        .set1("bar")
+        .build();
+    assert_eq!(
+        Labeled(
+            String::from("foo"),
+            Some(String::from("bar")),
+        ),
+        built,
+    );
+}
+
+#[test]
+fn builder_tuple_name_introspection() {
+    let builder = Rgb::builder()
+        .green(128);
+    assert!(builder.is_green_set());
+    assert!(! builder.is_red_set());
+    assert_eq!(
+        vec!["red", "blue"],
+        builder.missing_fields(),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Field red is missing")]
+fn builder_tuple_name_missing() {
+    Rgb::builder()
+        .green(128)
+        .blue(0)
+        .build();
+}
@@ -0,0 +1,108 @@
+use macon::Builder;
+
+// #############################################################################
+// ################################### INPUT ###################################
+// #############################################################################
+use std::path::PathBuf;
+
+#[derive(Builder)]
+#[builder(mode=Panic)]
+#[derive(PartialEq,Debug)]
+struct Foobar(
+    PathBuf,
+    Option<String>,
+);
+
+#[derive(Builder)]
+#[builder(mode=Panic)]
+#[derive(PartialEq,Debug)]
+struct Server(
+    #[builder(name=host)]
+    String,
+    #[builder(name=port)]
+    u16,
+);
+
+type Port = u16;
+
+#[derive(Builder)]
+#[builder(mode=Panic)]
+#[derive(PartialEq,Debug)]
+struct Aliased(
+    String,
+    ::std::string::String,
+    Port,
+    u16,
+);
+
+// #############################################################################
+// ################################### TESTS ###################################
+// #############################################################################
+
+#[test]
+fn builder_positional_set() {
+    let built = Foobar::builder()
+        .set(PathBuf::from("/tmp/positional"))
+        .set(String::from("foobar"))
+        .build();
+    assert_eq!(
+        Foobar(
+            PathBuf::from("/tmp/positional"),
+            Some(String::from("foobar")),
+        ),
+        built,
+    );
+}
+
+#[test]
+fn builder_positional_none() {
+    let built = Foobar::builder()
+        .set(PathBuf::from("/tmp/positional"))
+        .none()
+        .build();
+    assert_eq!(
+        Foobar(
+            PathBuf::from("/tmp/positional"),
+            None,
+        ),
+        built,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Field host can't be assigned from positional setter")]
+fn builder_positional_mismatch() {
+    Server::builder()
+        .set(8080u16)
+        .host("localhost")
+        .build();
+}
+
+#[test]
+#[should_panic(expected = "Too many positional values")]
+fn builder_positional_overflow() {
+    Foobar::builder()
+        .set(PathBuf::from("/tmp/positional"))
+        .none()
+        .none()
+        .build();
+}
+
+#[test]
+fn builder_positional_same_type_written_differently() {
+    let built = Aliased::builder()
+        .set::<_, 0>(String::from("foo"))
+        .set::<_, 1>(String::from("bar"))
+        .set::<_, 2>(8080)
+        .set::<_, 3>(9090)
+        .build();
+    assert_eq!(
+        Aliased(
+            String::from("foo"),
+            String::from("bar"),
+            8080,
+            9090,
+        ),
+        built,
+    );
+}
@@ -0,0 +1,137 @@
+use macon::Builder;
+
+// #############################################################################
+// ################################### INPUT ###################################
+// #############################################################################
+use std::path::PathBuf;
+
+#[derive(Builder)]
+#[builder(patch)]
+#[derive(PartialEq,Debug)]
+struct Foobar {
+    path: PathBuf,
+    port: u16,
+    name: Option<String>,
+    #[builder(Into=!)]
+    id: usize,
+}
+
+#[derive(Builder)]
+#[builder(patch, derive(Clone, Debug, PartialEq))]
+#[derive(PartialEq,Debug)]
+struct Tuple(
+    PathBuf,
+    Option<String>,
+);
+
+// #############################################################################
+// ################################### TESTS ###################################
+// #############################################################################
+
+fn foobar() -> Foobar {
+    Foobar {
+        path: PathBuf::from("/tmp/patch"),
+        port: 8080,
+        name: Some(String::from("foobar")),
+        id: 1,
+    }
+}
+
+#[test]
+fn named_patch_empty() {
+    let mut target = foobar();
+    FoobarPatch::default().apply(&mut target);
+    assert_eq!(foobar(), target);
+}
+
+#[test]
+fn named_patch_apply() {
+    let mut target = foobar();
+    FoobarPatch::default()
+        .port(9090u16)
+        .name_none()
+        .id(2)
+        .apply(&mut target);
+    assert_eq!(
+        Foobar {
+            path: PathBuf::from("/tmp/patch"),
+            port: 9090,
+            name: None,
+            id: 2,
+        },
+        target,
+    );
+}
+
+#[test]
+fn named_patch_merge() {
+    let mut target = foobar();
+    let file = FoobarPatch::default()
+        .path("/etc/patch")
+        .port(9090u16);
+    let cli = FoobarPatch::default()
+        .port(7070u16)
+        .name_optional(Some("cli"))
+        .port_default();
+    file.merge(cli).apply(&mut target);
+    assert_eq!(
+        Foobar {
+            path: PathBuf::from("/etc/patch"),
+            port: 0,
+            name: Some(String::from("cli")),
+            id: 1,
+        },
+        target,
+    );
+}
+
+#[test]
+fn tuple_patch() {
+    let mut target = Tuple(PathBuf::from("/tmp/patch"), None);
+    TuplePatch::default()
+        .set1("foobar")
+        .merge(TuplePatch::default().set0("/tmp/other"))
+        .apply(&mut target);
+    assert_eq!(
+        Tuple(PathBuf::from("/tmp/other"), Some(String::from("foobar"))),
+        target,
+    );
+}
+
+#[test]
+fn tuple_patch_derives() {
+    let patch = TuplePatch::default()
+        .set1("foobar");
+    assert_eq!(patch.clone(), patch);
+    assert_eq!("TuplePatch(None, Some(Some(\"foobar\")))", format!("{:?}", patch));
+}
+
+#[test]
+fn named_patch_merge_none() {
+    let mut target = foobar();
+    let file = FoobarPatch::default()
+        .name("file");
+    let cli = FoobarPatch::default()
+        .name_none();
+    file.merge(cli).apply(&mut target);
+    assert_eq!(
+        Foobar {
+            name: None,
+            ..foobar()
+        },
+        target,
+    );
+}
+
+#[test]
+fn tuple_patch_merge_none() {
+    let mut target = Tuple(PathBuf::from("/tmp/patch"), None);
+    TuplePatch::default()
+        .set1("foobar")
+        .merge(TuplePatch::default().set1_none())
+        .apply(&mut target);
+    assert_eq!(
+        Tuple(PathBuf::from("/tmp/patch"), None),
+        target,
+    );
+}
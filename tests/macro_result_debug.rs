@@ -0,0 +1,86 @@
+use macon::Builder;
+
+// #############################################################################
+// ################################### INPUT ###################################
+// #############################################################################
+use std::path::PathBuf;
+
+#[derive(Builder)]
+#[builder(mode=Result)]
+struct Foobar {
+    path: PathBuf,
+    port: u16,
+    name: Option<String>,
+    #[builder(sensitive)]
+    password: Option<String>,
+    #[builder(sensitive)]
+    token: PathBuf,
+}
+
+#[derive(Builder)]
+#[builder(mode=Result)]
+#[derive(Default)]
+struct WithDefault(
+    u16,
+    #[builder(sensitive)]
+    String,
+);
+
+struct NoDebug;
+
+#[derive(Builder)]
+#[builder(mode=Result)]
+struct WithNoDebug {
+    #[builder(Into=!)]
+    value: NoDebug,
+}
+
+// #############################################################################
+// ################################### TESTS ###################################
+// #############################################################################
+
+#[test]
+fn builder_debug_empty() {
+    assert_eq!(
+        "FoobarBuilder { path: Undefined, port: Default, name: Default, password: Default, token: Undefined }",
+        format!("{:?}", Foobar::builder()),
+    );
+}
+
+#[test]
+fn builder_debug_set() {
+    let builder = Foobar::builder()
+        .path("/tmp/debug")
+        .port(8080u16)
+        .name("foobar")
+        .password("secret")
+        .token("/tmp/token");
+    assert_eq!(
+        r#"FoobarBuilder { path: Set("/tmp/debug"), port: Set(8080), name: Set(Some("foobar")), password: Set(***), token: Set(***) }"#,
+        format!("{:?}", builder),
+    );
+}
+
+#[test]
+fn builder_debug_default_struct() {
+    assert_eq!(
+        "WithDefaultBuilder(Keep, Keep)",
+        format!("{:?}", WithDefault::builder()),
+    );
+    assert_eq!(
+        "WithDefaultBuilder(Set(8080), Set(***))",
+        format!("{:?}", WithDefault::builder().set0(8080u16).set1("secret")),
+    );
+    assert_eq!(
+        "WithDefaultBuilder(Default, Keep)",
+        format!("{:?}", WithDefault::builder().set0_default()),
+    );
+}
+
+#[test]
+fn builder_no_debug() {
+    assert!(WithNoDebug::builder()
+        .value(NoDebug)
+        .build()
+        .is_ok());
+}
@@ -0,0 +1,59 @@
+use macon::Builder;
+
+// #############################################################################
+// ################################### INPUT ###################################
+// #############################################################################
+use std::path::PathBuf;
+
+#[derive(Builder)]
+#[builder(mode=Result, derive(Clone, Debug, PartialEq), attr(must_use = "builder does nothing until built"))]
+#[derive(PartialEq,Debug)]
+struct Foobar {
+    #[builder(setter_attr(inline, must_use))]
+    path: PathBuf,
+    #[builder(setter_attr(inline), setter_attr(must_use))]
+    port: u16,
+    name: Option<String>,
+}
+
+#[derive(Builder)]
+#[builder(mode=Result, derive(Default, Clone, PartialEq), attr(doc = "Tuple builder"))]
+#[derive(PartialEq,Debug)]
+struct Tuple(
+    #[builder(setter_attr(inline))]
+    PathBuf,
+    Option<String>,
+);
+
+// #############################################################################
+// ################################### TESTS ###################################
+// #############################################################################
+
+#[test]
+fn builder_derives() {
+    let builder = Foobar::builder()
+        .path("/tmp/derive")
+        .port(8080u16);
+    let cloned = builder.clone();
+    assert_eq!(builder, cloned);
+    assert!(!format!("{:?}", builder).is_empty());
+    assert_eq!(
+        Foobar {
+            path: PathBuf::from("/tmp/derive"),
+            port: 8080,
+            name: None,
+        },
+        cloned.build().unwrap(),
+    );
+}
+
+#[test]
+fn builder_derives_tuple() {
+    let builder = Tuple::builder()
+        .set0("/tmp/derive");
+    assert!(builder.clone() == builder);
+    assert_eq!(
+        Tuple(PathBuf::from("/tmp/derive"), None),
+        builder.build().unwrap(),
+    );
+}
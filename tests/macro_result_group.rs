@@ -0,0 +1,199 @@
+use macon::Builder;
+
+// #############################################################################
+// ################################### INPUT ###################################
+// #############################################################################
+#[derive(Builder)]
+#[builder(mode=Result)]
+#[derive(PartialEq,Debug)]
+struct Connection {
+    host: String,
+    #[builder(group(auth, exactly_one))]
+    password: Option<String>,
+    #[builder(group(auth))]
+    token: Option<String>,
+    #[builder(group(proxy, at_most_one))]
+    http_proxy: Option<String>,
+    #[builder(group(proxy))]
+    socks_proxy: Option<String>,
+    #[builder(group(contact, at_least_one))]
+    email: Option<String>,
+    #[builder(group(contact))]
+    phone: Option<String>,
+}
+
+#[derive(Builder)]
+#[builder(mode=Result)]
+#[derive(Default,PartialEq,Debug)]
+struct DefaultConnection {
+    #[builder(group(auth, exactly_one))]
+    password: Option<String>,
+    #[builder(group(auth))]
+    token: Option<String>,
+}
+
+#[derive(Builder)]
+#[builder(mode=Result, Default)]
+#[derive(PartialEq,Debug)]
+struct PasswordConnection {
+    #[builder(group(auth, exactly_one))]
+    password: Option<String>,
+    #[builder(group(auth))]
+    token: Option<String>,
+}
+
+impl Default for PasswordConnection {
+    fn default() -> Self {
+        Self {
+            password: Some(String::from("default")),
+            token: None,
+        }
+    }
+}
+
+// #############################################################################
+// ################################### TESTS ###################################
+// #############################################################################
+
+#[test]
+fn builder_build_full() {
+    let built = Connection::builder()
+        .host("localhost")
+        .token("secret")
+        .http_proxy("http://proxy")
+        .email("admin@localhost")
+        .phone("0123456789")
+        .build();
+    assert_eq!(
+        Ok(Connection {
+            host: String::from("localhost"),
+            password: None,
+            token: Some(String::from("secret")),
+            http_proxy: Some(String::from("http://proxy")),
+            socks_proxy: None,
+            email: Some(String::from("admin@localhost")),
+            phone: Some(String::from("0123456789")),
+        }),
+        built,
+    );
+}
+
+#[test]
+fn builder_build_optional_none() {
+    let built = Connection::builder()
+        .host("localhost")
+        .password_none()
+        .token_optional(Some("secret"))
+        .email("admin@localhost")
+        .build();
+    assert_eq!(
+        Ok(Connection {
+            host: String::from("localhost"),
+            password: None,
+            token: Some(String::from("secret")),
+            http_proxy: None,
+            socks_proxy: None,
+            email: Some(String::from("admin@localhost")),
+            phone: None,
+        }),
+        built,
+    );
+}
+
+#[test]
+fn builder_build_exactly_one_missing() {
+    let built = Connection::builder()
+        .host("localhost")
+        .email("admin@localhost")
+        .build();
+    assert_eq!(
+        Err(String::from("Group auth requires exactly one of password, token to be set")),
+        built,
+    );
+}
+
+#[test]
+fn builder_build_exactly_one_many() {
+    let built = Connection::builder()
+        .host("localhost")
+        .password("secret")
+        .token("secret")
+        .email("admin@localhost")
+        .build();
+    assert_eq!(
+        Err(String::from("Group auth requires exactly one of password, token to be set")),
+        built,
+    );
+}
+
+#[test]
+fn builder_build_all_errors() {
+    let built = Connection::builder()
+        .http_proxy("http://proxy")
+        .socks_proxy("socks5://proxy")
+        .build();
+    assert_eq!(
+        Err([
+            "Group auth requires exactly one of password, token to be set",
+            "Group proxy requires at most one of http_proxy, socks_proxy to be set",
+            "Group contact requires at least one of email, phone to be set",
+        ].join("\n")),
+        built,
+    );
+}
+
+#[test]
+fn builder_build_default_struct() {
+    let built = DefaultConnection::builder()
+        .token("secret")
+        .build();
+    assert_eq!(
+        Ok(DefaultConnection {
+            password: None,
+            token: Some(String::from("secret")),
+        }),
+        built,
+    );
+}
+
+#[test]
+fn builder_build_default_struct_missing() {
+    let built = DefaultConnection::builder()
+        .build();
+    assert_eq!(
+        Err(String::from("Group auth requires exactly one of password, token to be set")),
+        built,
+    );
+}
+
+#[test]
+fn builder_into_missing() {
+    let built: Result<Connection, _> = Connection::builder()
+        .host("localhost")
+        .password("secret")
+        .try_into();
+    assert_eq!(
+        Err(String::from("Group contact requires at least one of email, phone to be set")),
+        built,
+    );
+}
+
+#[test]
+fn builder_build_default_instance_member() {
+    let built = PasswordConnection::builder()
+        .build();
+    assert_eq!(
+        Ok(PasswordConnection {
+            password: Some(String::from("default")),
+            token: None,
+        }),
+        built,
+    );
+    let built = PasswordConnection::builder()
+        .token("secret")
+        .build();
+    assert_eq!(
+        Err(String::from("Group auth requires exactly one of password, token to be set")),
+        built,
+    );
+}
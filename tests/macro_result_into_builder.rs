@@ -0,0 +1,74 @@
+use macon::Builder;
+
+// #############################################################################
+// ################################### INPUT ###################################
+// #############################################################################
+use std::path::PathBuf;
+
+#[derive(Builder)]
+#[builder(mode=Result)]
+#[derive(Clone,PartialEq,Debug)]
+struct Foobar {
+    path: PathBuf,
+    port: u16,
+    name: Option<String>,
+}
+
+#[derive(Builder)]
+#[builder(mode=Result)]
+#[derive(Default,PartialEq,Debug)]
+struct WithDefault(
+    usize,
+    Option<String>,
+);
+
+// #############################################################################
+// ################################### TESTS ###################################
+// #############################################################################
+
+#[test]
+fn target_into_builder() {
+    let target = Foobar {
+        path: PathBuf::from("/tmp/into_builder"),
+        port: 8080,
+        name: Some(String::from("foobar")),
+    };
+    let built = target
+        .into_builder()
+        .port(9090u16)
+        .name_none()
+        .build();
+    assert_eq!(
+        Ok(Foobar {
+            path: PathBuf::from("/tmp/into_builder"),
+            port: 9090,
+            name: None,
+        }),
+        built,
+    );
+}
+
+#[test]
+fn target_to_builder() {
+    let target = Foobar {
+        path: PathBuf::from("/tmp/to_builder"),
+        port: 8080,
+        name: None,
+    };
+    let builder = target.to_builder();
+    assert!(builder.is_path_set(), "is_path_set");
+    assert!(builder.is_port_set(), "is_port_set");
+    assert!(builder.is_name_set(), "is_name_set");
+    assert_eq!(Ok(target), builder.build());
+}
+
+#[test]
+fn builder_from_target() {
+    let builder = WithDefaultBuilder::from(WithDefault(42, Some(String::from("foobar"))));
+    assert_eq!(Some(&42), builder.get0());
+    assert_eq!(Some(&Some(String::from("foobar"))), builder.get1());
+    assert_eq!(
+        Ok(WithDefault(42, Some(String::from("foobar")))),
+        builder.build(),
+    );
+}
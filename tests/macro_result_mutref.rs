@@ -0,0 +1,172 @@
+use macon::Builder;
+
+// #############################################################################
+// ################################### INPUT ###################################
+// #############################################################################
+use std::path::PathBuf;
+
+#[derive(Builder)]
+#[builder(mode=Result, style=MutRef)]
+#[derive(PartialEq,Debug)]
+struct Foobar {
+    path: PathBuf,
+    port: u16,
+    name: Option<String>,
+}
+
+#[derive(Builder)]
+#[builder(mode=Result, style=MutRef)]
+#[derive(Default,PartialEq,Debug)]
+struct WithDefault {
+    port: u16,
+    name: Option<String>,
+}
+
+#[derive(Builder)]
+#[builder(mode=Result, style=MutRef)]
+#[derive(PartialEq,Debug)]
+struct Tuple(
+    PathBuf,
+    Option<String>,
+);
+
+struct Parser {
+    builder: FoobarBuilder,
+}
+
+// #############################################################################
+// ################################### TESTS ###################################
+// #############################################################################
+
+#[test]
+fn builder_mutref_chain() {
+    let mut builder = Foobar::builder();
+    builder
+        .path("/tmp/mutref")
+        .port(8080u16);
+    assert_eq!(
+        Foobar {
+            path: PathBuf::from("/tmp/mutref"),
+            port: 8080,
+            name: None,
+        },
+        builder.build().unwrap(),
+    );
+    builder.name("foobar");
+    assert_eq!(
+        Foobar {
+            path: PathBuf::from("/tmp/mutref"),
+            port: 8080,
+            name: Some(String::from("foobar")),
+        },
+        builder.build().unwrap(),
+    );
+}
+
+#[test]
+fn builder_mutref_loop() {
+    let mut parser = Parser {
+        builder: Foobar::builder(),
+    };
+    for (key, value) in [("path", "/tmp/loop"), ("name", "foobar"), ("port", "9090")] {
+        match key {
+            "path" => parser.builder.path(value),
+            "port" => parser.builder.port(value.parse::<u16>().unwrap()),
+            "name" => parser.builder.name(value),
+            _ => unreachable!(),
+        };
+    }
+    assert_eq!(
+        Foobar {
+            path: PathBuf::from("/tmp/loop"),
+            port: 9090,
+            name: Some(String::from("foobar")),
+        },
+        parser.builder.build().unwrap(),
+    );
+}
+
+#[test]
+fn builder_mutref_default_struct() {
+    let mut builder = WithDefault::builder();
+    builder.port(8080u16).name_keep();
+    assert_eq!(
+        WithDefault {
+            port: 8080,
+            name: None,
+        },
+        builder.build().unwrap(),
+    );
+}
+
+#[test]
+fn builder_mutref_tuple() {
+    let mut builder = Tuple::builder();
+    builder.set0("/tmp/tuple").set1_none();
+    assert_eq!(
+        Tuple(PathBuf::from("/tmp/tuple"), None),
+        builder.build().unwrap(),
+    );
+}
+
+#[test]
+fn builder_mutref_merge() {
+    let mut builder = Foobar::builder();
+    builder.path("/tmp/self").port(8080u16);
+    let mut other = Foobar::builder();
+    other.port(9090u16).name("other");
+    builder.merge(other);
+    let mut fallback = Foobar::builder();
+    fallback.path("/tmp/fallback");
+    builder.or(fallback);
+    assert_eq!(
+        Foobar {
+            path: PathBuf::from("/tmp/self"),
+            port: 9090,
+            name: Some(String::from("other")),
+        },
+        builder.build().unwrap(),
+    );
+}
+
+#[test]
+fn builder_mutref_missing() {
+    let builder = Foobar::builder();
+    assert_eq!(
+        Err(String::from("Field path is missing")),
+        builder.build(),
+    );
+}
+
+#[test]
+fn builder_mutref_trait() {
+    fn finish<B: macon::Builder>(builder: B) -> B::Output {
+        builder.build()
+    }
+    let mut builder = Foobar::builder();
+    builder.path("/tmp/trait");
+    assert!(finish(&builder).is_ok());
+    assert!(builder.build().is_ok());
+}
+
+#[test]
+fn builder_mutref_buildable() {
+    fn make<T, O>(fill: impl FnOnce(&mut T::Builder)) -> O
+    where
+        T: macon::Buildable,
+        for<'a> &'a T::Builder: macon::Builder<Target = T, Output = O>,
+    {
+        let mut builder = T::builder();
+        fill(&mut builder);
+        macon::Builder::build(&builder)
+    }
+    assert_eq!(
+        WithDefault {
+            port: 8080,
+            name: None,
+        },
+        make::<WithDefault, _>(|builder| {
+            builder.port(8080u16);
+        }).unwrap(),
+    );
+}
@@ -0,0 +1,214 @@
+use macon::Builder;
+
+// #############################################################################
+// ################################### INPUT ###################################
+// #############################################################################
+use std::path::PathBuf;
+
+#[derive(Builder)]
+#[builder(mode=Result)]
+#[derive(PartialEq,Debug)]
+struct Foobar(
+    PathBuf,
+    Option<String>,
+    u16,
+    #[builder(Default)]
+    u16,
+);
+
+#[derive(Builder)]
+#[builder(mode=Result)]
+#[derive(Default,PartialEq,Debug)]
+struct WithDefault(
+    u16,
+    String,
+);
+
+#[derive(Builder)]
+#[builder(mode=Result, style=MutRef)]
+#[derive(PartialEq,Debug)]
+struct Rgb(
+    #[builder(name=red)]
+    u8,
+    #[builder(name=green)]
+    u8,
+    #[builder(name=blue)]
+    u8,
+);
+
+type Port = u16;
+
+#[derive(Builder)]
+#[builder(mode=Result)]
+#[derive(PartialEq,Debug)]
+struct Aliased(
+    String,
+    ::std::string::String,
+    Port,
+    u16,
+);
+
+// #############################################################################
+// ################################### TESTS ###################################
+// #############################################################################
+
+#[test]
+fn builder_positional_set() {
+    let built = Foobar::builder()
+        .set(PathBuf::from("/tmp/positional"))
+        .set(String::from("foobar"))
+        .set(8080)
+        .set(9090)
+        .build();
+    assert_eq!(
+        Ok(Foobar(
+            PathBuf::from("/tmp/positional"),
+            Some(String::from("foobar")),
+            8080,
+            9090,
+        )),
+        built,
+    );
+}
+
+#[test]
+fn builder_positional_none_default() {
+    let built = Foobar::builder()
+        .set(PathBuf::from("/tmp/positional"))
+        .none()
+        .set(8080)
+        .default()
+        .build();
+    assert_eq!(
+        Ok(Foobar(
+            PathBuf::from("/tmp/positional"),
+            None,
+            8080,
+            0,
+        )),
+        built,
+    );
+}
+
+#[test]
+fn builder_positional_keep() {
+    let built = WithDefault::builder()
+        .keep()
+        .set(String::from("foobar"))
+        .build();
+    assert_eq!(
+        Ok(WithDefault(0, String::from("foobar"))),
+        built,
+    );
+}
+
+#[test]
+fn builder_positional_mismatch() {
+    let built = Foobar::builder()
+        .set(PathBuf::from("/tmp/positional"))
+        .set(8080)
+        .set(9090)
+        .build();
+    assert_eq!(
+        Err(String::from("Field 1 can't be assigned from positional setter")),
+        built,
+    );
+}
+
+#[test]
+fn builder_positional_unsupported() {
+    let built = Foobar::builder()
+        .none()
+        .set(String::from("foobar"))
+        .set(8080)
+        .set(9090)
+        .build();
+    assert_eq!(
+        Err(String::from("Field 0 is missing\nField 0 can't be assigned from positional setter")),
+        built,
+    );
+}
+
+#[test]
+fn builder_positional_overflow() {
+    let built = WithDefault::builder()
+        .set(8080)
+        .set(String::from("foobar"))
+        .set(String::from("overflow"))
+        .build();
+    assert_eq!(
+        Err(String::from("Too many positional values")),
+        built,
+    );
+}
+
+#[test]
+fn builder_positional_mixed() {
+    let built = Foobar::builder()
+        .set3(9090u16)
+        .set(PathBuf::from("/tmp/positional"))
+        .none()
+        .set(8080)
+        .build();
+    assert_eq!(
+        Ok(Foobar(
+            PathBuf::from("/tmp/positional"),
+            None,
+            8080,
+            9090,
+        )),
+        built,
+    );
+}
+
+#[test]
+fn builder_positional_mutref() {
+    let mut builder = Rgb::builder();
+    for value in [255, 128] {
+        builder.set(value);
+    }
+    assert_eq!(
+        Ok(Rgb(255, 128, 0)),
+        builder.build(),
+    );
+    builder.set(64);
+    assert_eq!(
+        Ok(Rgb(255, 128, 64)),
+        builder.build(),
+    );
+    builder.set(32);
+    assert_eq!(
+        Err(String::from("Too many positional values")),
+        builder.build(),
+    );
+}
+
+#[test]
+fn builder_positional_same_type_written_differently() {
+    let built = Aliased::builder()
+        .set::<_, 0>(String::from("foo"))
+        .set::<_, 1>(String::from("bar"))
+        .set::<_, 2>(8080)
+        .set::<_, 3>(9090)
+        .build();
+    assert_eq!(
+        Ok(Aliased(
+            String::from("foo"),
+            String::from("bar"),
+            8080,
+            9090,
+        )),
+        built,
+    );
+
+    let built = Aliased::builder()
+        .set::<_, 1>(String::from("foo"))
+        .set1("bar")
+        .set2(8080u16)
+        .set3(9090u16)
+        .build();
+    assert_eq!(
+        Err(String::from("Field 0 can't be assigned from positional setter")),
+        built,
+    );
+}
@@ -0,0 +1,106 @@
+use macon::Builder;
+
+// #############################################################################
+// ################################### INPUT ###################################
+// #############################################################################
+use std::path::PathBuf;
+
+#[derive(Builder)]
+#[builder(mode=Typestate, clone)]
+#[derive(PartialEq,Debug)]
+struct Foobar {
+    path: PathBuf,
+    port: u16,
+    name: Option<String>,
+}
+
+#[derive(Builder)]
+#[builder(mode=Typestate)]
+#[derive(Clone,Default,PartialEq,Debug)]
+struct WithDefault(
+    u16,
+    Option<String>,
+);
+
+#[derive(Builder)]
+#[builder(mode=Typestate)]
+struct NoClone {
+    #[builder(Into=!)]
+    f: Box<dyn Fn(usize) -> usize>,
+}
+
+// #############################################################################
+// ################################### TESTS ###################################
+// #############################################################################
+
+#[test]
+fn builder_clone() {
+    let template = Foobar::builder()
+        .path("/tmp/template")
+        .port(8080u16);
+    let first = template
+        .clone()
+        .name("first")
+        .build();
+    let second = template
+        .name("second")
+        .build();
+    assert_eq!(
+        Foobar {
+            path: PathBuf::from("/tmp/template"),
+            port: 8080,
+            name: Some(String::from("first")),
+        },
+        first,
+    );
+    assert_eq!(
+        Foobar {
+            path: PathBuf::from("/tmp/template"),
+            port: 8080,
+            name: Some(String::from("second")),
+        },
+        second,
+    );
+}
+
+#[test]
+fn builder_build_cloned() {
+    let template = Foobar::builder()
+        .path("/tmp/template")
+        .port(8080u16);
+    let built: Vec<Foobar> = (0..3)
+        .map(|_| template.build_cloned())
+        .collect();
+    assert_eq!(3, built.len());
+    assert!(built.iter().all(|foobar| foobar.path.as_path() == std::path::Path::new("/tmp/template") && foobar.port == 8080));
+    assert_eq!(
+        Foobar {
+            path: PathBuf::from("/tmp/template"),
+            port: 8080,
+            name: None,
+        },
+        template.build(),
+    );
+}
+
+#[test]
+fn builder_build_cloned_derive() {
+    let template = WithDefault::builder()
+        .set0(8080u16);
+    assert_eq!(
+        WithDefault(8080, None),
+        template.build_cloned(),
+    );
+    assert_eq!(
+        WithDefault(8080, None),
+        template.build(),
+    );
+}
+
+#[test]
+fn builder_no_clone() {
+    let built = NoClone::builder()
+        .f(Box::new(|x| x + 1))
+        .build();
+    assert_eq!((built.f)(1), 2);
+}
@@ -0,0 +1,176 @@
+use macon::Builder;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// #############################################################################
+// ################################### INPUT ###################################
+// #############################################################################
+
+#[derive(Builder)]
+#[builder(const)]
+#[derive(Default,PartialEq,Debug)]
+struct Limits {
+    max: usize,
+    name: &'static str,
+    timeout: Option<u64>,
+    tags: Vec<&'static str>,
+}
+
+#[derive(Builder)]
+#[builder(const)]
+#[derive(PartialEq,Debug)]
+struct Point(
+    i32,
+    i32,
+    Option<String>,
+);
+
+#[derive(Builder)]
+#[builder(const)]
+#[derive(PartialEq,Debug)]
+struct Endpoint {
+    #[builder(group(address, exactly_one))]
+    host: Option<&'static str>,
+    #[builder(group(address))]
+    socket: Option<&'static str>,
+    port: u16,
+}
+
+static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(PartialEq,Debug)]
+struct Counted(u8);
+
+impl Drop for Counted {
+    fn drop(&mut self) {
+        DROPS.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[derive(Builder)]
+#[builder(const, dynamic)]
+#[derive(PartialEq,Debug)]
+struct Resources {
+    first: Counted,
+    second: Counted,
+    third: Option<Counted>,
+}
+
+static DEFAULT_LIMITS: Limits = Limits::builder()
+    .max(10)
+    .name("default")
+    .tags(Vec::new())
+    .build();
+
+const ORIGIN: Point = Point::builder()
+    .set(0)
+    .set(0)
+    .none()
+    .build();
+
+const UNIT: Point = Point::builder()
+    .set1(1)
+    .set0(1)
+    .set2_none()
+    .build();
+
+const LOCALHOST: Endpoint = Endpoint::builder()
+    .port(8080)
+    .host("localhost")
+    .build();
+
+// #############################################################################
+// ################################### TESTS ###################################
+// #############################################################################
+
+#[test]
+fn builder_const_static() {
+    assert_eq!(
+        Limits {
+            max: 10,
+            name: "default",
+            timeout: None,
+            tags: vec![],
+        },
+        DEFAULT_LIMITS,
+    );
+}
+
+#[test]
+fn builder_const_runtime() {
+    assert_eq!(
+        Limits {
+            max: 20,
+            name: "runtime",
+            timeout: Some(30),
+            tags: vec!["a", "b"],
+        },
+        Limits::builder()
+            .tags(vec!["a", "b"])
+            .timeout(30)
+            .name("runtime")
+            .max(20)
+            .build(),
+    );
+}
+
+#[test]
+fn builder_const_tuple_ordered() {
+    assert_eq!(
+        Point(0, 0, None),
+        ORIGIN,
+    );
+}
+
+#[test]
+fn builder_const_tuple_unordered() {
+    assert_eq!(
+        Point(1, 1, None),
+        UNIT,
+    );
+}
+
+#[test]
+fn builder_const_tuple_drop() {
+    assert_eq!(
+        Point(1, 2, Some(String::from("point"))),
+        Point::builder()
+            .set(1)
+            .set(2)
+            .set(String::from("point"))
+            .build(),
+    );
+}
+
+#[test]
+fn builder_const_group() {
+    assert_eq!(
+        Endpoint {
+            host: Some("localhost"),
+            socket: None,
+            port: 8080,
+        },
+        LOCALHOST,
+    );
+}
+
+#[test]
+fn builder_const_dynamic_drop() {
+    let built = Resources::builder()
+        .first(Counted(1))
+        .second(Counted(2))
+        .into_dynamic()
+        .third(Counted(3))
+        .build();
+    assert_eq!(0, DROPS.load(Ordering::SeqCst));
+    assert_eq!(
+        Ok(Resources {
+            first: Counted(1),
+            second: Counted(2),
+            third: Some(Counted(3)),
+        }),
+        built,
+    );
+    drop(built);
+    // 3 expected values (dropped by assertion) + 3 built values
+    assert_eq!(6, DROPS.load(Ordering::SeqCst));
+}
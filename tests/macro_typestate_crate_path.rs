@@ -0,0 +1,56 @@
+use macon::Builder;
+
+// #############################################################################
+// ################################### INPUT ###################################
+// #############################################################################
+use std::path::PathBuf;
+
+mod my_prelude {
+    pub use ::macon as runtime;
+}
+
+#[derive(Builder)]
+#[builder(crate = my_prelude::runtime)]
+#[derive(PartialEq,Debug)]
+struct Foobar {
+    path: PathBuf,
+    port: u16,
+    name: Option<String>,
+}
+
+#[derive(Builder)]
+#[builder(mode=Panic, crate = crate::my_prelude::runtime)]
+#[derive(Default,PartialEq,Debug)]
+struct WithDefault(
+    u16,
+    Option<String>,
+);
+
+// #############################################################################
+// ################################### TESTS ###################################
+// #############################################################################
+
+#[test]
+fn builder_crate_path() {
+    assert_eq!(
+        Foobar {
+            path: PathBuf::from("/tmp/crate"),
+            port: 0,
+            name: Some(String::from("foobar")),
+        },
+        Foobar::builder()
+            .path("/tmp/crate")
+            .name("foobar")
+            .build(),
+    );
+}
+
+#[test]
+fn builder_crate_path_default() {
+    assert_eq!(
+        WithDefault(8080, None),
+        WithDefault::builder()
+            .set0(8080u16)
+            .build(),
+    );
+}
@@ -45,6 +45,26 @@ fn named_build_default_implicit() {
     )
 }
 
+#[test]
+fn named_build_default_overwrite() {
+    let built = StructNamed::builder()
+        .id(1)
+        .value("first")
+        .mandatory("/dev/null")
+        .id_default()
+        .value("second")
+        .build();
+    assert_eq!(
+        StructNamed {
+            id: 0,
+            value: String::from("second"),
+            optional: None,
+            mandatory: PathBuf::from("/dev/null"),
+        },
+        built,
+    )
+}
+
 #[test]
 fn named_build_default_explicit() {
     let built = StructNamed::builder()
@@ -0,0 +1,111 @@
+use macon::Builder;
+use std::path::PathBuf;
+
+// #############################################################################
+// ################################### INPUT ###################################
+// #############################################################################
+#[derive(Builder)]
+#[builder(mode=Typestate, dynamic)]
+#[derive(PartialEq,Debug)]
+struct Server {
+    host: PathBuf,
+    port: u16,
+    name: Option<String>,
+    #[builder(group(auth, exactly_one))]
+    password: Option<String>,
+    #[builder(group(auth))]
+    token: Option<String>,
+}
+
+#[derive(Builder)]
+#[builder(dynamic)]
+#[derive(PartialEq,Debug)]
+struct Pair(
+    PathBuf,
+    Option<PathBuf>,
+);
+
+fn apply(builder: ServerDynamicBuilder, entries: &[(&str, &str)]) -> ServerDynamicBuilder {
+    entries
+        .iter()
+        .fold(builder, |builder, (key, value)| match *key {
+            "host" => builder.host(*value),
+            "port" => builder.port(value.parse::<u16>().unwrap()),
+            "name" => builder.name(*value),
+            "password" => builder.password(*value),
+            "token" => builder.token(*value),
+            _ => builder,
+        })
+}
+
+// #############################################################################
+// ################################### TESTS ###################################
+// #############################################################################
+
+#[test]
+fn builder_dynamic_ok() {
+    let builder = Server::builder()
+        .host("localhost")
+        .into_dynamic();
+    let built = apply(builder, &[("port", "8080"), ("token", "secret")]).build();
+    assert_eq!(
+        Ok(Server {
+            host: PathBuf::from("localhost"),
+            port: 8080,
+            name: None,
+            password: None,
+            token: Some(String::from("secret")),
+        }),
+        built,
+    );
+}
+
+#[test]
+fn builder_dynamic_missing() {
+    let built = apply(Server::builder().into_dynamic(), &[("name", "foo")]).build();
+    assert_eq!(
+        Err(String::from("Field host is missing\nGroup auth requires exactly one of password, token to be set")),
+        built,
+    );
+}
+
+#[test]
+fn builder_dynamic_default() {
+    let built = apply(ServerDynamicBuilder::default(), &[("host", "localhost"), ("password", "secret")]).build();
+    assert_eq!(
+        Ok(Server {
+            host: PathBuf::from("localhost"),
+            port: 0,
+            name: None,
+            password: Some(String::from("secret")),
+            token: None,
+        }),
+        built,
+    );
+}
+
+#[test]
+fn builder_dynamic_tuple() {
+    let built = Pair::builder()
+        .set1("second")
+        .into_dynamic()
+        .set0("first")
+        .build();
+    assert_eq!(
+        Ok(Pair(PathBuf::from("first"), Some(PathBuf::from("second")))),
+        built,
+    );
+}
+
+#[test]
+fn builder_dynamic_tuple_positional() {
+    let built = Pair::builder()
+        .set1("second")
+        .into_dynamic()
+        .set(PathBuf::from("first"))
+        .build();
+    assert_eq!(
+        Ok(Pair(PathBuf::from("first"), Some(PathBuf::from("second")))),
+        built,
+    );
+}
@@ -0,0 +1,96 @@
+use macon::Builder;
+
+// #############################################################################
+// ################################### INPUT ###################################
+// #############################################################################
+#[derive(Builder)]
+#[builder(mode=Typestate)]
+#[derive(PartialEq,Debug)]
+struct Server {
+    host: String,
+    #[builder(explicit)]
+    tls: Option<String>,
+}
+
+#[derive(Builder)]
+#[builder(mode=Typestate)]
+#[derive(PartialEq,Debug)]
+struct Tuple(
+    String,
+    #[builder(explicit)]
+    Option<String>,
+);
+
+// #############################################################################
+// ################################### TESTS ###################################
+// #############################################################################
+
+#[test]
+fn builder_explicit_value() {
+    let built = Server::builder()
+        .host("localhost")
+        .tls("cert.pem")
+        .build();
+    assert_eq!(
+        Server {
+            host: String::from("localhost"),
+            tls: Some(String::from("cert.pem")),
+        },
+        built,
+    );
+}
+
+#[test]
+fn builder_explicit_none() {
+    let built = Server::builder()
+        .host("localhost")
+        .tls_none()
+        .build();
+    assert_eq!(
+        Server {
+            host: String::from("localhost"),
+            tls: None,
+        },
+        built,
+    );
+}
+
+#[test]
+fn builder_explicit_optional() {
+    let built = Server::builder()
+        .host("localhost")
+        .tls_optional(None::<String>)
+        .build();
+    assert_eq!(
+        Server {
+            host: String::from("localhost"),
+            tls: None,
+        },
+        built,
+    );
+}
+
+#[test]
+fn builder_explicit_tuple() {
+    let built = Tuple::builder()
+        .set0("localhost")
+        .set1_none()
+        .build();
+    assert_eq!(
+        Tuple(
+            String::from("localhost"),
+            None,
+        ),
+        built,
+    );
+}
+
+#[test]
+fn builder_explicit_missing_fields() {
+    let builder = Server::builder()
+        .host("localhost");
+    assert_eq!(
+        vec!["tls"],
+        builder.missing_fields(),
+    );
+}
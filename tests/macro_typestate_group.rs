@@ -0,0 +1,170 @@
+use macon::Builder;
+
+// #############################################################################
+// ################################### INPUT ###################################
+// #############################################################################
+#[derive(Builder)]
+#[builder(mode=Typestate)]
+#[derive(PartialEq,Debug)]
+struct Connection {
+    host: String,
+    #[builder(group(auth, exactly_one))]
+    password: Option<String>,
+    #[builder(group(auth))]
+    token: Option<String>,
+    #[builder(group(proxy, at_most_one))]
+    http_proxy: Option<String>,
+    #[builder(group(proxy))]
+    socks_proxy: Option<String>,
+    #[builder(group(contact, at_least_one))]
+    email: Option<String>,
+    #[builder(group(contact))]
+    phone: Option<String>,
+}
+
+// Group wider than 64 members (typestates are counted, not enumerated)
+macro_rules! wide {
+    ($($field:ident)*) => {
+        #[derive(Builder)]
+        struct Wide {
+            $(
+                #[builder(group(wide, at_least_one))]
+                $field: Option<u8>,
+            )*
+        }
+    };
+}
+
+wide!(
+    f00 f01 f02 f03 f04 f05 f06 f07 f08 f09 f10
+    f11 f12 f13 f14 f15 f16 f17 f18 f19 f20 f21
+    f22 f23 f24 f25 f26 f27 f28 f29 f30 f31 f32
+    f33 f34 f35 f36 f37 f38 f39 f40 f41 f42 f43
+    f44 f45 f46 f47 f48 f49 f50 f51 f52 f53 f54
+    f55 f56 f57 f58 f59 f60 f61 f62 f63 f64 f65
+);
+
+// #############################################################################
+// ################################### TESTS ###################################
+// #############################################################################
+
+#[test]
+fn builder_build_exactly_one() {
+    let built = Connection::builder()
+        .host("localhost")
+        .token("secret")
+        .email("admin@localhost")
+        .build();
+    assert_eq!(
+        Connection {
+            host: String::from("localhost"),
+            password: None,
+            token: Some(String::from("secret")),
+            http_proxy: None,
+            socks_proxy: None,
+            email: Some(String::from("admin@localhost")),
+            phone: None,
+        },
+        built,
+    );
+}
+
+#[test]
+fn builder_build_at_most_one() {
+    let built = Connection::builder()
+        .host("localhost")
+        .password("secret")
+        .socks_proxy("socks5://proxy")
+        .phone("0123456789")
+        .build();
+    assert_eq!(
+        Connection {
+            host: String::from("localhost"),
+            password: Some(String::from("secret")),
+            token: None,
+            http_proxy: None,
+            socks_proxy: Some(String::from("socks5://proxy")),
+            email: None,
+            phone: Some(String::from("0123456789")),
+        },
+        built,
+    );
+}
+
+#[test]
+fn builder_build_at_least_one() {
+    let built = Connection::builder()
+        .phone("0123456789")
+        .host("localhost")
+        .email("admin@localhost")
+        .password("secret")
+        .build();
+    assert_eq!(
+        Connection {
+            host: String::from("localhost"),
+            password: Some(String::from("secret")),
+            token: None,
+            http_proxy: None,
+            socks_proxy: None,
+            email: Some(String::from("admin@localhost")),
+            phone: Some(String::from("0123456789")),
+        },
+        built,
+    );
+}
+
+#[test]
+fn builder_into() {
+    let built: Connection = Connection::builder()
+        .host("localhost")
+        .password("secret")
+        .email("admin@localhost")
+        .into();
+    assert_eq!(
+        Connection {
+            host: String::from("localhost"),
+            password: Some(String::from("secret")),
+            token: None,
+            http_proxy: None,
+            socks_proxy: None,
+            email: Some(String::from("admin@localhost")),
+            phone: None,
+        },
+        built,
+    );
+}
+
+#[test]
+fn builder_build_wide_group() {
+    let built = Wide::builder()
+        .f65(65u8)
+        .f00(0u8)
+        .build();
+    assert_eq!(Some(0), built.f00);
+    assert_eq!(None, built.f01);
+    assert_eq!(Some(65), built.f65);
+}
+
+#[test]
+fn builder_build_unset_members() {
+    let built = Connection::builder()
+        .host("localhost")
+        .password_none()
+        .token("secret")
+        .http_proxy_optional(Some("http://proxy"))
+        .email_default()
+        .phone("0123456789")
+        .build();
+    assert_eq!(
+        Connection {
+            host: String::from("localhost"),
+            password: None,
+            token: Some(String::from("secret")),
+            http_proxy: Some(String::from("http://proxy")),
+            socks_proxy: None,
+            email: None,
+            phone: Some(String::from("0123456789")),
+        },
+        built,
+    );
+}
@@ -0,0 +1,68 @@
+use macon::Builder;
+
+// #############################################################################
+// ################################### INPUT ###################################
+// #############################################################################
+use std::path::PathBuf;
+
+#[derive(Builder)]
+#[builder(mode=Typestate)]
+#[derive(Clone,PartialEq,Debug)]
+struct Foobar {
+    path: PathBuf,
+    port: u16,
+    name: Option<String>,
+}
+
+#[derive(Builder)]
+#[builder(mode=Typestate)]
+#[derive(Default,PartialEq,Debug)]
+struct WithDefault(
+    usize,
+    Option<String>,
+);
+
+// #############################################################################
+// ################################### TESTS ###################################
+// #############################################################################
+
+#[test]
+fn target_into_builder() {
+    let target = Foobar {
+        path: PathBuf::from("/tmp/into_builder"),
+        port: 8080,
+        name: Some(String::from("foobar")),
+    };
+    let builder: FoobarBuilder<macon::Set> = target.into_builder();
+    assert!(builder.missing_fields().is_empty());
+    assert_eq!(
+        Foobar {
+            path: PathBuf::from("/tmp/into_builder"),
+            port: 8080,
+            name: Some(String::from("foobar")),
+        },
+        builder.build(),
+    );
+}
+
+#[test]
+fn target_to_builder() {
+    let target = Foobar {
+        path: PathBuf::from("/tmp/to_builder"),
+        port: 8080,
+        name: None,
+    };
+    let built = target.to_builder().build();
+    assert_eq!(target, built);
+}
+
+#[test]
+fn builder_from_target() {
+    let builder = WithDefaultBuilder::from(WithDefault(42, Some(String::from("foobar"))));
+    assert!(builder.is_set0(), "is_set0");
+    assert!(builder.is_set1(), "is_set1");
+    assert_eq!(
+        WithDefault(42, Some(String::from("foobar"))),
+        builder.build(),
+    );
+}
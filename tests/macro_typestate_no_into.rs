@@ -10,6 +10,22 @@ struct Foobar {
   f: Box<dyn Fn(usize) -> usize>,
 }
 
+#[derive(Builder)]
+#[builder(mode=Typestate,)]
+struct OptionFoobar {
+  #[builder(Into=!)]
+  f: Option<Box<dyn Fn(usize) -> usize>>,
+}
+
+#[derive(Builder)]
+#[builder(mode=Typestate,)]
+struct Tuple(
+  #[builder(Into=!)]
+  Box<dyn Fn(usize) -> usize>,
+  #[builder(Into=!)]
+  Option<Box<dyn Fn(usize) -> usize>>,
+);
+
 // #############################################################################
 // ################################### TESTS ###################################
 // #############################################################################
@@ -21,3 +37,26 @@ fn builder_build() {
         .build();
     assert_eq!((built.f)(1), 2);
 }
+
+#[test]
+fn builder_build_option() {
+    let built = OptionFoobar::builder()
+        .f(Box::new(|x| x + 1))
+        .build();
+    assert_eq!(built.f.map(|f| f(1)), Some(2));
+
+    let built = OptionFoobar::builder()
+        .f_optional(Some(Box::new(|x| x + 2)))
+        .build();
+    assert_eq!(built.f.map(|f| f(1)), Some(3));
+}
+
+#[test]
+fn builder_build_tuple_ordered() {
+    let built = Tuple::builder()
+        .set(Box::new(|x| x * 2))
+        .set(Box::new(|x| x * 3))
+        .build();
+    assert_eq!((built.0)(2), 4);
+    assert_eq!(built.1.map(|f| f(2)), Some(6));
+}
@@ -85,6 +85,23 @@ fn named_builder_build_partial_explicit() {
     );
 }
 
+#[test]
+fn named_builder_build_overwrite() {
+    let built = Named::builder()
+        .option("/tmp/builder_build_overwrite/first")
+        .mandatory("/tmp/builder_build_overwrite/mandatory")
+        .option_none()
+        .option("/tmp/builder_build_overwrite/option")
+        .build();
+    assert_eq!(
+        Named {
+            mandatory: PathBuf::from("/tmp/builder_build_overwrite/mandatory"),
+            option: Some(PathBuf::from("/tmp/builder_build_overwrite/option")),
+        },
+        built,
+    );
+}
+
 #[test]
 fn named_builder_into_full() {
     let built = Named::builder()
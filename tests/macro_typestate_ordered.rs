@@ -0,0 +1,86 @@
+use macon::Builder;
+use std::path::PathBuf;
+
+// #############################################################################
+// ################################### INPUT ###################################
+// #############################################################################
+#[derive(Builder)]
+#[builder(mode=Typestate, ordered)]
+#[derive(PartialEq,Debug)]
+struct Server {
+    host: PathBuf,
+    #[builder(Into=!)]
+    port: u16,
+    name: Option<String>,
+    path: PathBuf,
+    comment: Option<String>,
+}
+
+#[derive(Builder)]
+#[builder(ordered, const)]
+#[derive(PartialEq,Debug)]
+struct Range {
+    start: usize,
+    end: usize,
+}
+
+const RANGE: Range = Range::builder()
+    .start(1)
+    .end(10)
+    .build();
+
+// #############################################################################
+// ################################### TESTS ###################################
+// #############################################################################
+
+#[test]
+fn builder_ordered_all() {
+    let built = Server::builder()
+        .host("localhost")
+        .port(8080)
+        .name("foo")
+        .path("/var/www")
+        .comment("bar")
+        .build();
+    assert_eq!(
+        Server {
+            host: PathBuf::from("localhost"),
+            port: 8080,
+            name: Some(String::from("foo")),
+            path: PathBuf::from("/var/www"),
+            comment: Some(String::from("bar")),
+        },
+        built,
+    );
+}
+
+#[test]
+fn builder_ordered_variants() {
+    let built = Server::builder()
+        .host("localhost")
+        .port_default()
+        .name_none()
+        .path("/var/www")
+        .build();
+    assert_eq!(
+        Server {
+            host: PathBuf::from("localhost"),
+            port: 0,
+            name: None,
+            path: PathBuf::from("/var/www"),
+            comment: None,
+        },
+        built,
+    );
+}
+
+#[test]
+fn builder_ordered_const() {
+    assert_eq!(
+        Range {
+            start: 1,
+            end: 10,
+        },
+        RANGE,
+    );
+}
@@ -0,0 +1,115 @@
+use macon::Builder;
+use std::path::PathBuf;
+
+// #############################################################################
+// ################################### INPUT ###################################
+// #############################################################################
+#[derive(Builder)]
+#[builder(mode=Typestate, overwrite)]
+#[derive(PartialEq,Debug)]
+struct Server {
+    host: PathBuf,
+    #[builder(Into=!)]
+    port: u16,
+    name: Option<String>,
+}
+
+#[derive(Builder)]
+#[builder(mode=Typestate)]
+#[derive(PartialEq,Debug)]
+struct Client {
+    #[builder(overwrite)]
+    host: PathBuf,
+    #[builder(Into=!)]
+    port: u16,
+    #[builder(overwrite, group(auth, exactly_one))]
+    password: Option<String>,
+    #[builder(group(auth))]
+    token: Option<String>,
+}
+
+#[derive(Builder)]
+#[builder(mode=Typestate, overwrite)]
+#[derive(PartialEq,Debug)]
+struct Pair(
+    PathBuf,
+    #[builder(overwrite=!, Into=!)]
+    u16,
+);
+
+fn with_defaults(builder: ServerBuilderEmpty) -> ServerBuilderComplete {
+    builder
+        .host("localhost")
+        .port(80)
+}
+
+// #############################################################################
+// ################################### TESTS ###################################
+// #############################################################################
+
+#[test]
+fn builder_overwrite_struct() {
+    let built = Server::builder()
+        .port(80)
+        .host("localhost")
+        .port(8080)
+        .name("foo")
+        .name_none()
+        .build();
+    assert_eq!(
+        Server {
+            host: PathBuf::from("localhost"),
+            port: 8080,
+            name: None,
+        },
+        built,
+    );
+}
+
+#[test]
+fn builder_overwrite_defaults() {
+    let built = with_defaults(Server::builder())
+        .port(8080)
+        .build();
+    assert_eq!(
+        Server {
+            host: PathBuf::from("localhost"),
+            port: 8080,
+            name: None,
+        },
+        built,
+    );
+}
+
+#[test]
+fn builder_overwrite_field() {
+    let built = Client::builder()
+        .host("localhost")
+        .password("foo")
+        .port(80)
+        .host("remote")
+        .password("bar")
+        .build();
+    assert_eq!(
+        Client {
+            host: PathBuf::from("remote"),
+            port: 80,
+            password: Some(String::from("bar")),
+            token: None,
+        },
+        built,
+    );
+}
+
+#[test]
+fn builder_overwrite_tuple() {
+    let built = Pair::builder()
+        .set0("localhost")
+        .set1(80)
+        .set0("remote")
+        .build();
+    assert_eq!(
+        Pair(PathBuf::from("remote"), 80),
+        built,
+    );
+}
@@ -0,0 +1,130 @@
+use macon::Builder;
+use std::path::PathBuf;
+
+// #############################################################################
+// ################################### INPUT ###################################
+// #############################################################################
+#[derive(Builder)]
+#[builder(mode=Typestate)]
+#[derive(PartialEq,Debug)]
+struct Server {
+    host: PathBuf,
+    #[builder(Into=!, Default=!)]
+    port: u16,
+    name: Option<String>,
+}
+
+#[derive(Builder)]
+#[builder(mode=Typestate)]
+#[derive(PartialEq,Debug)]
+struct Pair(
+    PathBuf,
+    PathBuf,
+);
+
+struct Secret(String);
+
+#[derive(Builder)]
+pub struct Vault {
+    secret: Secret,
+    name: Option<String>,
+}
+
+fn with_port<B: ServerBuilderNeedsPort>(builder: B) -> B::WithPort {
+    builder.port(8080)
+}
+
+fn with_host<B: ServerBuilderNeedsHost>(builder: B) -> impl ServerBuilderHasHost {
+    builder.host("localhost")
+}
+
+fn finish<B>(builder: B) -> Server
+where
+    B: ServerBuilderHasHost + macon::Builder<Target=Server, Output=Server>,
+{
+    macon::Builder::build(builder)
+}
+
+fn is_complete<B: ServerBuilderHasHost>(_builder: &B) -> bool {
+    true
+}
+
+// #############################################################################
+// ################################### TESTS ###################################
+// #############################################################################
+
+#[test]
+fn builder_type_empty() {
+    let builder: ServerBuilderEmpty = Server::builder();
+    assert!(!builder.is_host_set());
+}
+
+#[test]
+fn builder_type_complete() {
+    let builder: ServerBuilderComplete = Server::builder()
+        .host("localhost")
+        .port(8080)
+        .name("foo");
+    assert!(is_complete(&builder));
+    assert_eq!(
+        Server {
+            host: PathBuf::from("localhost"),
+            port: 8080,
+            name: Some(String::from("foo")),
+        },
+        builder.build(),
+    );
+}
+
+#[test]
+fn builder_trait_needs() {
+    let built = with_port(Server::builder().name_none())
+        .host("localhost")
+        .build();
+    assert_eq!(
+        Server {
+            host: PathBuf::from("localhost"),
+            port: 8080,
+            name: None,
+        },
+        built,
+    );
+}
+
+#[test]
+fn builder_trait_has() {
+    let built = finish(with_port(Server::builder()).host("localhost"));
+    assert_eq!(
+        Server {
+            host: PathBuf::from("localhost"),
+            port: 8080,
+            name: None,
+        },
+        built,
+    );
+    let _opaque = with_host(Server::builder());
+}
+
+#[test]
+fn builder_trait_tuple() {
+    fn with_first<B: PairBuilderNeedsV0>(builder: B) -> B::WithV0 {
+        builder.set0("first")
+    }
+    let builder: PairBuilderComplete = with_first(Pair::builder()).set1("second");
+    assert_eq!(
+        Pair(PathBuf::from("first"), PathBuf::from("second")),
+        builder.build(),
+    );
+}
+
+#[test]
+fn builder_trait_private_field_type() {
+    fn with_secret<B: VaultBuilderNeedsSecret>(builder: B) -> B::WithSecret {
+        builder.secret(Secret(String::from("secret")))
+    }
+    let built = with_secret(Vault::builder())
+        .name("vault")
+        .build();
+    assert_eq!("secret", built.secret.0);
+    assert_eq!(Some(String::from("vault")), built.name);
+}
@@ -0,0 +1,82 @@
+use macon::Builder;
+
+// #############################################################################
+// ################################### INPUT ###################################
+// #############################################################################
+use std::path::PathBuf;
+
+#[derive(Builder)]
+#[builder(withers)]
+#[derive(PartialEq,Debug)]
+struct Foobar {
+    path: PathBuf,
+    port: u16,
+    name: Option<String>,
+    #[builder(Into=!)]
+    id: usize,
+}
+
+#[derive(Builder)]
+#[builder(withers)]
+#[derive(PartialEq,Debug)]
+struct Tuple(
+    PathBuf,
+    Option<String>,
+);
+
+// #############################################################################
+// ################################### TESTS ###################################
+// #############################################################################
+
+#[test]
+fn named_withers() {
+    let target = Foobar {
+        path: PathBuf::from("/tmp/withers"),
+        port: 8080,
+        name: None,
+        id: 1,
+    };
+    let target = target
+        .with_path("/tmp/other")
+        .with_port(9090u16)
+        .with_name("foobar")
+        .with_id(2);
+    assert_eq!(
+        Foobar {
+            path: PathBuf::from("/tmp/other"),
+            port: 9090,
+            name: Some(String::from("foobar")),
+            id: 2,
+        },
+        target,
+    );
+}
+
+#[test]
+fn named_withers_option() {
+    let target = Foobar {
+        path: PathBuf::from("/tmp/withers"),
+        port: 8080,
+        name: Some(String::from("foobar")),
+        id: 1,
+    };
+    let target = target.with_name_none();
+    assert_eq!(None, target.name);
+    let target = target.with_name_optional(Some("barfoo"));
+    assert_eq!(Some(String::from("barfoo")), target.name);
+}
+
+#[test]
+fn tuple_withers() {
+    let target = Tuple(PathBuf::from("/tmp/withers"), None)
+        .with0("/tmp/other")
+        .with1("foobar");
+    assert_eq!(
+        Tuple(PathBuf::from("/tmp/other"), Some(String::from("foobar"))),
+        target,
+    );
+    assert_eq!(
+        Tuple(PathBuf::from("/tmp/other"), None),
+        target.with1_none(),
+    );
+}